@@ -0,0 +1,134 @@
+//! Self-play calibration harness: play two `EngineConfig`s against each
+//! other, alternating colors, so the MCTS parameters can be tuned
+//! empirically instead of hand-picked. Pure Rust, no `wasm_bindgen` surface,
+//! so it runs natively (`cargo test --features calibrate -- --ignored --nocapture`)
+//! rather than through the WASM build.
+
+use crate::{EngineConfig, GameState, MCTSEngine, Player};
+
+/// One `EngineConfig` field a challenger can nudge away from the incumbent.
+#[derive(Clone, Copy, Debug)]
+enum Param {
+    PlayoutHeuristicChance,
+    SelectionTemperature,
+}
+
+const PARAMS: [Param; 2] = [Param::PlayoutHeuristicChance, Param::SelectionTemperature];
+
+fn next_rand(seed: &mut u64) -> u64 {
+    *seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+    *seed
+}
+
+/// Nudges one field of `config` up or down by a fixed step, clamped to the
+/// range the field is sensible in.
+fn mutate(config: EngineConfig, param: Param, seed: &mut u64) -> EngineConfig {
+    let direction = if next_rand(seed) % 2 == 0 { 1.0 } else { -1.0 };
+    let mut next = config;
+    match param {
+        Param::PlayoutHeuristicChance => {
+            next.playout_heuristic_chance = (next.playout_heuristic_chance + direction * 0.05).clamp(0.0, 1.0);
+        }
+        Param::SelectionTemperature => {
+            next.selection_temperature = (next.selection_temperature + direction * 0.05).max(0.0);
+        }
+    }
+    next
+}
+
+/// Plays one full game, `first` moving first, each side's move chosen by
+/// its own `MCTSEngine` under its own config. Returns the winner, or
+/// `Player::None` on a full board with nobody connected (shouldn't happen
+/// on a real Hex board, but `search` can in principle run out of moves).
+fn play_game(first: EngineConfig, second: EngineConfig, time_limit_ms: u32) -> Player {
+    let mut state = GameState::new();
+    let mut to_move = Player::Human;
+    let configs = [first, second];
+    let mut which = 0usize;
+
+    loop {
+        let winner = state.clone().check_winner();
+        if winner != Player::None {
+            return winner;
+        }
+        if state.is_full() {
+            return Player::None;
+        }
+
+        let config = configs[which];
+        let mut engine = MCTSEngine::new(state.clone(), to_move, config);
+        let result = engine.search(time_limit_ms);
+
+        let Some(best) = result.best_move else { return Player::None };
+        state.make_move(best.r * crate::BOARD_COLS + best.c, to_move);
+
+        to_move = to_move.opponent();
+        which = 1 - which;
+    }
+}
+
+/// Plays `games` games with colors alternated each game so neither config
+/// gets the first-move advantage every time, and returns the challenger's
+/// win rate in `[0, 1]`.
+fn win_rate(incumbent: EngineConfig, challenger: EngineConfig, games: u32, time_limit_ms: u32) -> f64 {
+    let mut wins = 0.0;
+    for game in 0..games {
+        let (first, second, challenger_is) = if game % 2 == 0 {
+            (challenger, incumbent, Player::Human)
+        } else {
+            (incumbent, challenger, Player::AI)
+        };
+        match play_game(first, second, time_limit_ms) {
+            w if w == challenger_is => wins += 1.0,
+            Player::None => wins += 0.5,
+            _ => {}
+        }
+    }
+    wins / games as f64
+}
+
+/// Generate-and-sort hill climb: repeatedly mutate one parameter of the
+/// incumbent to produce a challenger, play a fixed match, and keep the
+/// challenger if it wins by more than `margin`. Returns the final config
+/// plus a log of every round that was tried, for the CLI table below.
+fn calibrate(rounds: u32, games_per_round: u32, time_limit_ms: u32, margin: f64) -> (EngineConfig, Vec<(Param, f64, bool)>) {
+    let mut incumbent = EngineConfig::for_difficulty(5);
+    let mut seed = 0x9E3779B97F4A7C15u64;
+    let mut log = Vec::with_capacity(rounds as usize);
+
+    for _ in 0..rounds {
+        let param = PARAMS[(next_rand(&mut seed) % PARAMS.len() as u64) as usize];
+        let challenger = mutate(incumbent, param, &mut seed);
+
+        let rate = win_rate(incumbent, challenger, games_per_round, time_limit_ms);
+        let adopted = rate > 0.5 + margin;
+        if adopted {
+            incumbent = challenger;
+        }
+        log.push((param, rate, adopted));
+    }
+
+    (incumbent, log)
+}
+
+/// CLI/test entry point: runs a short calibration and prints the resulting
+/// parameter table. Kept cheap (few rounds, few games, short time budget)
+/// since this exercises the full self-play loop rather than a pre-tuned
+/// config - a real tuning run would raise all three. `#[ignore]`d like
+/// `tuning.rs`'s self-play drivers (`cargo test --features calibrate --
+/// --ignored --nocapture`), since it plays real games rather than asserting
+/// a fixed-size fact.
+#[test]
+#[ignore]
+fn run_calibration() {
+    let (tuned, log) = calibrate(10, 4, 50, 0.1);
+
+    println!("round  param                      win_rate  adopted");
+    for (i, (param, rate, adopted)) in log.iter().enumerate() {
+        println!("{:>5}  {:<25?}  {:>7.2}  {}", i, param, rate, adopted);
+    }
+    println!(
+        "final config: max_simulations={} playout_heuristic_chance={:.2} selection_temperature={:.2}",
+        tuned.max_simulations, tuned.playout_heuristic_chance, tuned.selection_temperature
+    );
+}
@@ -1,26 +1,34 @@
+#[cfg(feature = "calibrate")]
+mod calibrate;
+
 use wasm_bindgen::prelude::*;
 use rand::prelude::*;
 use serde::{Serialize, Deserialize};
 
 // --- Constants ---
-const BOARD_ROWS: usize = 11;
-const BOARD_COLS: usize = 11;
+pub const BOARD_ROWS: usize = 11;
+pub const BOARD_COLS: usize = 11;
 const NUM_CELLS: usize = BOARD_ROWS * BOARD_COLS;
 // Virtual nodes for Union-Find
 const VIRTUAL_TOP: usize = NUM_CELLS;
 const VIRTUAL_BOTTOM: usize = NUM_CELLS + 1;
 const VIRTUAL_LEFT: usize = NUM_CELLS;
 const VIRTUAL_RIGHT: usize = NUM_CELLS + 1;
+// Sentinel for `GameState::connection_distance` when every path between a
+// player's two edges is blocked - larger than any real path cost (at most
+// `NUM_CELLS` steps) but well clear of overflow when summed/weighted.
+const UNREACHABLE_DISTANCE: i32 = 1_000_000;
 
 // --- Config Struct ---
-struct EngineConfig {
-    max_simulations: u32,
-    playout_heuristic_chance: f64,
-    selection_temperature: f64, // 0.0 = Deterministic, >0.0 = Softmax
+#[derive(Clone, Copy)]
+pub struct EngineConfig {
+    pub max_simulations: u32,
+    pub playout_heuristic_chance: f64,
+    pub selection_temperature: f64, // 0.0 = Deterministic, >0.0 = Softmax
 }
 
 impl EngineConfig {
-    fn for_difficulty(level: u32) -> Self {
+    pub fn for_difficulty(level: u32) -> Self {
         match level {
             3 => EngineConfig {
                 max_simulations: 30_000,
@@ -50,7 +58,7 @@ pub enum Player {
 }
 
 impl Player {
-    fn opponent(&self) -> Player {
+    pub fn opponent(&self) -> Player {
         match self {
             Player::Human => Player::AI,
             Player::AI => Player::Human,
@@ -62,20 +70,20 @@ impl Player {
 // --- Analysis Types ---
 #[derive(Serialize)]
 pub struct MoveInfo {
-    r: usize,
-    c: usize,
-    visits: u32,
-    wins: u32,
-    win_rate: f64,
+    pub r: usize,
+    pub c: usize,
+    pub visits: u32,
+    pub wins: u32,
+    pub win_rate: f64,
 }
 
 #[derive(Serialize)]
 pub struct AnalysisResult {
-    best_move: Option<MoveInfo>,
-    alternatives: Vec<MoveInfo>,
-    total_simulations: u32,
-    elapsed_ms: f64,
-    nps: f64,
+    pub best_move: Option<MoveInfo>,
+    pub alternatives: Vec<MoveInfo>,
+    pub total_simulations: u32,
+    pub elapsed_ms: f64,
+    pub nps: f64,
 }
 
 // --- Union-Find ---
@@ -128,10 +136,10 @@ impl UnionFind {
 
 // --- Game State ---
 #[derive(Clone)]
-struct GameState {
+pub struct GameState {
     board: Vec<Player>,
     empty_cells: Vec<usize>,
-    empty_cells_map: Vec<usize>, 
+    empty_cells_map: Vec<usize>,
     uf_human: UnionFind,
     uf_ai: UnionFind,
     last_move: Option<usize>,
@@ -139,7 +147,7 @@ struct GameState {
 }
 
 impl GameState {
-    fn new() -> Self {
+    pub fn new() -> Self {
         let board = vec![Player::None; NUM_CELLS];
         let empty_cells: Vec<usize> = (0..NUM_CELLS).collect();
         let empty_cells_map: Vec<usize> = (0..NUM_CELLS).collect();
@@ -195,7 +203,7 @@ impl GameState {
         neighbors
     }
 
-    fn make_move(&mut self, idx: usize, player: Player) {
+    pub fn make_move(&mut self, idx: usize, player: Player) {
         self.board[idx] = player;
         self.last_move = Some(idx);
         
@@ -226,12 +234,74 @@ impl GameState {
         self.turn_count += 1;
     }
 
-    fn check_winner(&mut self) -> Player {
+    pub fn check_winner(&mut self) -> Player {
         if self.uf_human.connected(VIRTUAL_LEFT, VIRTUAL_RIGHT) { return Player::Human; }
         if self.uf_ai.connected(VIRTUAL_TOP, VIRTUAL_BOTTOM) { return Player::AI; }
         Player::None
     }
 
+    pub fn is_full(&self) -> bool {
+        self.empty_cells.is_empty()
+    }
+
+    /// Hex "two-distance": the minimum cost to connect `player`'s source
+    /// edge (top for AI, left for Human - same edges `init_virtual_connections`
+    /// uses) to the opposite edge, where stepping onto an empty cell costs 1,
+    /// onto one of `player`'s own stones costs 0, and onto an opponent stone
+    /// is impassable. A completed connection costs 0; `UNREACHABLE_DISTANCE`
+    /// is returned if every path is blocked. This is the global counterpart
+    /// to the purely local `evaluate_bridge_potential`.
+    fn connection_distance(&self, player: Player) -> i32 {
+        let opponent = player.opponent();
+        let on_near_edge = |idx: usize| match player {
+            Player::AI => idx / BOARD_COLS == 0,
+            Player::Human => idx % BOARD_COLS == 0,
+            Player::None => false,
+        };
+        let on_far_edge = |idx: usize| match player {
+            Player::AI => idx / BOARD_COLS == BOARD_ROWS - 1,
+            Player::Human => idx % BOARD_COLS == BOARD_COLS - 1,
+            Player::None => false,
+        };
+        let step_cost = |idx: usize| -> Option<i32> {
+            match self.board[idx] {
+                p if p == opponent => None,
+                p if p == player => Some(0),
+                _ => Some(1),
+            }
+        };
+
+        let mut dist = vec![UNREACHABLE_DISTANCE; NUM_CELLS];
+        let mut heap = std::collections::BinaryHeap::new();
+
+        for idx in 0..NUM_CELLS {
+            if !on_near_edge(idx) { continue; }
+            if let Some(cost) = step_cost(idx) {
+                if cost < dist[idx] {
+                    dist[idx] = cost;
+                    heap.push(std::cmp::Reverse((cost, idx)));
+                }
+            }
+        }
+
+        while let Some(std::cmp::Reverse((cost, idx))) = heap.pop() {
+            if cost > dist[idx] { continue; }
+            if on_far_edge(idx) { return cost; }
+
+            for n in Self::get_neighbors(idx) {
+                if let Some(step) = step_cost(n) {
+                    let next_cost = cost + step;
+                    if next_cost < dist[n] {
+                        dist[n] = next_cost;
+                        heap.push(std::cmp::Reverse((next_cost, n)));
+                    }
+                }
+            }
+        }
+
+        UNREACHABLE_DISTANCE
+    }
+
     fn evaluate_bridge_potential(&self, idx: usize, player: Player) -> i32 {
         let neighbors = Self::get_neighbors(idx);
         let opponent = player.opponent();
@@ -252,6 +322,74 @@ impl GameState {
         if opp_neighbors >= 2 { score += 60; }
         score
     }
+
+    /// Bridges the stone at `idx` forms with another friendly stone: pairs
+    /// of empty "carrier" cells such that occupying either one keeps `idx`
+    /// connected to that partner, since the opponent can only ever take one
+    /// of the two. Found generically by reusing `get_neighbors` rather than
+    /// a hardcoded offset table: a cell two hops from `idx` is a bridge
+    /// partner exactly when it shares precisely two (empty) neighbors with
+    /// `idx`.
+    fn bridge_carriers(&self, idx: usize) -> smallvec::SmallVec<[(usize, usize); 6]> {
+        let player = self.board[idx];
+        let mut carriers = smallvec::SmallVec::new();
+        if player == Player::None {
+            return carriers;
+        }
+
+        let own_neighbors = Self::get_neighbors(idx);
+        let mut seen = smallvec::SmallVec::<[usize; 12]>::new();
+
+        for &n in &own_neighbors {
+            for cand in Self::get_neighbors(n) {
+                if cand == idx || seen.contains(&cand) {
+                    continue;
+                }
+                seen.push(cand);
+
+                if self.board[cand] != player {
+                    continue;
+                }
+
+                let cand_neighbors = Self::get_neighbors(cand);
+                let common: smallvec::SmallVec<[usize; 2]> = own_neighbors
+                    .iter()
+                    .copied()
+                    .filter(|c| cand_neighbors.contains(c))
+                    .collect();
+
+                if common.len() == 2 && common.iter().all(|&c| self.board[c] == Player::None) {
+                    carriers.push((common[0], common[1]));
+                }
+            }
+        }
+
+        carriers
+    }
+}
+
+/// If `last` (the move the opponent just played) fills one carrier of a
+/// bridge anchored at one of `player`'s stones, returns the other carrier -
+/// playing it re-secures the connection instead of leaving it to chance.
+fn find_save_bridge(state: &GameState, player: Player, last: usize) -> Option<usize> {
+    // `bridge_carriers` only reports pairs whose carriers are both empty, so
+    // look for the bridge on the board as it was before `last` was played.
+    let mut before = state.clone();
+    before.board[last] = Player::None;
+    for n in GameState::get_neighbors(last) {
+        if state.board[n] != player {
+            continue;
+        }
+        for (c1, c2) in before.bridge_carriers(n) {
+            if c1 == last && state.board[c2] == Player::None {
+                return Some(c2);
+            }
+            if c2 == last && state.board[c1] == Player::None {
+                return Some(c1);
+            }
+        }
+    }
+    None
 }
 
 // --- MCTS Node ---
@@ -270,14 +408,14 @@ struct MCTSNode {
     player: Player,
 }
 
-struct MCTSEngine {
+pub struct MCTSEngine {
     nodes: Vec<MCTSNode>,
     root_state: GameState,
     config: EngineConfig,
 }
 
 impl MCTSEngine {
-    fn new(state: GameState, player: Player, config: EngineConfig) -> Self {
+    pub fn new(state: GameState, player: Player, config: EngineConfig) -> Self {
         let root = MCTSNode {
             move_idx: None,
             parent: None,
@@ -289,7 +427,7 @@ impl MCTSEngine {
             untried_moves: state.empty_cells.clone(),
             player: player.opponent(),
         };
-        
+
         MCTSEngine {
             nodes: vec![root],
             root_state: state,
@@ -297,6 +435,118 @@ impl MCTSEngine {
         }
     }
 
+    /// Re-root by descending through `moves` - one hop per move_idx, in
+    /// order - discarding every sibling subtree along the way (each
+    /// reflects a move that wasn't actually played) and compacting the
+    /// surviving subtree into a fresh `nodes` vec indexed from 0 so the
+    /// discarded ones don't linger in memory for the rest of the game.
+    /// Returns `None` (instead of mutating `self`) as soon as a hop has no
+    /// matching child - the tree was never expanded that far, or the board
+    /// diverged from this tree's history - so the caller can fall back to a
+    /// fresh tree for this ply. `config` is the current call's (difficulty
+    /// may have changed since the tree was built), not the stale one it was
+    /// searched with.
+    fn reroot(&self, state: &GameState, moves: &[usize], config: EngineConfig) -> Option<MCTSEngine> {
+        let mut idx = 0;
+        for &mv in moves {
+            idx = self.nodes[idx]
+                .children
+                .iter()
+                .copied()
+                .find(|&c| self.nodes[c].move_idx == Some(mv))?;
+        }
+
+        let mut nodes = Vec::with_capacity(self.nodes.len());
+        Self::compact_subtree(&self.nodes, idx, None, &mut nodes);
+        nodes[0].move_idx = None;
+
+        Some(MCTSEngine {
+            nodes,
+            root_state: state.clone(),
+            config,
+        })
+    }
+
+    /// Diffs `new_state`'s board against this tree's own `root_state` to
+    /// recover the moves played since it was built, then `reroot`s through
+    /// them in play order. Bails out to `None` - rather than guessing - the
+    /// moment the diff isn't exactly "one cell each flipped from empty to
+    /// the mover who's about to move first, then the other" (more than two
+    /// cells changed, a cell was cleared, or both changed cells belong to
+    /// the same player): that means the board was edited or reloaded rather
+    /// than advanced by ordinary play, so the previous tree's history no
+    /// longer applies.
+    fn reroot_from_board(&self, new_state: &GameState, config: EngineConfig) -> Option<MCTSEngine> {
+        let old_board = &self.root_state.board;
+        let new_board = &new_state.board;
+        if old_board.len() != new_board.len() {
+            return None;
+        }
+
+        let mut changed = Vec::new();
+        for i in 0..old_board.len() {
+            if old_board[i] != new_board[i] {
+                if old_board[i] != Player::None || new_board[i] == Player::None {
+                    return None;
+                }
+                changed.push((i, new_board[i]));
+            }
+        }
+        if changed.len() > 2 {
+            return None;
+        }
+
+        // Root's own `player` field follows the same convention as every
+        // other node's: the side who moved *into* it. So the side to move
+        // first out of this root is its opponent.
+        let first_mover = self.nodes[0].player.opponent();
+
+        let mut moves = Vec::new();
+        for mover in [first_mover, first_mover.opponent()] {
+            if let Some(&(idx, _)) = changed.iter().find(|&&(_, p)| p == mover) {
+                moves.push(idx);
+            }
+        }
+        if moves.len() != changed.len() {
+            return None;
+        }
+
+        self.reroot(new_state, &moves, config)
+    }
+
+    /// Recursively copies the subtree rooted at `src_idx` in `src` into
+    /// `dst`, fixing up `parent`/`children` to the new indices. Returns the
+    /// index the subtree root was placed at in `dst`.
+    fn compact_subtree(
+        src: &[MCTSNode],
+        src_idx: usize,
+        new_parent: Option<usize>,
+        dst: &mut Vec<MCTSNode>,
+    ) -> usize {
+        let node = &src[src_idx];
+        let dst_idx = dst.len();
+        dst.push(MCTSNode {
+            move_idx: node.move_idx,
+            parent: new_parent,
+            children: Vec::new(),
+            wins: node.wins,
+            visits: node.visits,
+            rave_wins: node.rave_wins,
+            rave_visits: node.rave_visits,
+            untried_moves: node.untried_moves.clone(),
+            player: node.player,
+        });
+
+        let children = node
+            .children
+            .iter()
+            .map(|&child| Self::compact_subtree(src, child, Some(dst_idx), dst))
+            .collect();
+        dst[dst_idx].children = children;
+
+        dst_idx
+    }
+
     fn expand(&mut self, node_idx: usize, state: &mut GameState) -> usize {
         let node = &mut self.nodes[node_idx];
         if node.untried_moves.is_empty() { return node_idx; }
@@ -377,12 +627,21 @@ impl MCTSEngine {
             }
             if state.empty_cells.is_empty() { return (Player::None, vec![]); }
 
+            // Save-bridge reflex: if the opponent just played into one
+            // carrier of one of our bridges, playing the other carrier
+            // preserves the virtual connection - a sharp, well-known
+            // improvement to Hex playout quality, so it takes priority over
+            // the generic heuristic below.
+            let save_bridge = state.last_move.and_then(|last| find_save_bridge(state, current_player, last));
+
             // Dynamic Playout Policy based on Difficulty
             let use_heuristic = rng.gen_bool(self.config.playout_heuristic_chance);
-            
+
             let move_idx;
-            
-            if use_heuristic && state.empty_cells.len() < 80 {
+
+            if let Some(m) = save_bridge.filter(|_| rng.gen_bool(0.9)) {
+                move_idx = m;
+            } else if use_heuristic && state.empty_cells.len() < 80 {
                 // Heuristic pick: Try to pick a move that blocks opponent or connects self
                 // Simple implementation: Check random 5 moves, pick best
                 let mut best_m = state.empty_cells[0];
@@ -426,7 +685,7 @@ impl MCTSEngine {
         }
     }
 
-    fn search(&mut self, time_limit_ms: u32) -> AnalysisResult {
+    pub fn search(&mut self, time_limit_ms: u32) -> AnalysisResult {
         let start = js_sys::Date::now();
         let mut iterations = 0;
         let rave_const = 300.0;
@@ -580,29 +839,648 @@ impl MCTSEngine {
     }
 }
 
+// --- Minimax (alpha-beta) backend ---
+//
+// A deterministic alternative to `MCTSEngine` for the lower difficulty
+// levels, and a useful cross-check against MCTS's stochastic output at the
+// top level. Unlike MCTS it doesn't sample playouts - it walks a
+// depth-limited game tree and scores the leaves with a tunable linear
+// evaluation instead.
+
+/// Weights for the linear position evaluation `minimax` scores leaves with.
+/// Kept separate from `EngineConfig` since it tunes a different backend.
+struct ScoreConfig {
+    connection_weight: f64,
+    bridge_weight: f64,
+    centrality_weight: f64,
+    mobility_weight: f64,
+    victory_weight: f64,
+}
+
+impl ScoreConfig {
+    fn default_weights() -> Self {
+        ScoreConfig {
+            connection_weight: 5.0,
+            bridge_weight: 2.0,
+            centrality_weight: 1.0,
+            mobility_weight: 0.5,
+            victory_weight: 1_000_000.0,
+        }
+    }
+}
+
+fn minimax_depth_for_difficulty(level: u32) -> u32 {
+    match level {
+        3 => 2,
+        5 => 3,
+        7 | _ => 4,
+    }
+}
+
+/// Sum of `evaluate_bridge_potential` over every empty cell, from `player`'s
+/// point of view. Cells away from any stones score zero, so this mostly
+/// measures latent bridge structure near the player's existing groups.
+fn bridge_score(state: &GameState, player: Player) -> f64 {
+    state
+        .empty_cells
+        .iter()
+        .map(|&idx| state.evaluate_bridge_potential(idx, player) as f64)
+        .sum()
+}
+
+fn centrality_score(state: &GameState, player: Player) -> f64 {
+    let center_r = BOARD_ROWS as i32 / 2;
+    let center_c = BOARD_COLS as i32 / 2;
+    let max_dist = center_r + center_c;
+
+    state
+        .board
+        .iter()
+        .enumerate()
+        .filter(|&(_, &p)| p == player)
+        .map(|(i, _)| {
+            let r = (i / BOARD_COLS) as i32;
+            let c = (i % BOARD_COLS) as i32;
+            let dist = (r - center_r).abs() + (c - center_c).abs();
+            (max_dist - dist) as f64
+        })
+        .sum()
+}
+
+/// Number of empty cells adjacent to at least one of `player`'s stones -
+/// how many ways they have to extend a group next turn.
+fn mobility_score(state: &GameState, player: Player) -> f64 {
+    let mut frontier = std::collections::HashSet::new();
+    for (i, &p) in state.board.iter().enumerate() {
+        if p == player {
+            for n in GameState::get_neighbors(i) {
+                if state.board[n] == Player::None {
+                    frontier.insert(n);
+                }
+            }
+        }
+    }
+    frontier.len() as f64
+}
+
+/// `connection_distance`, clamped to the board size: an unreachable edge
+/// reads as "about as bad as it can get" without the `UNREACHABLE_DISTANCE`
+/// sentinel swamping the other evaluation terms.
+fn bounded_connection_distance(state: &GameState, player: Player) -> i32 {
+    state.connection_distance(player).min(NUM_CELLS as i32)
+}
+
+/// Linear evaluation from the AI's point of view: positive favors the AI,
+/// negative favors the human. `state` needs `&mut` because `check_winner`
+/// walks the union-find (path compression mutates it).
+fn evaluate(state: &mut GameState, cfg: &ScoreConfig) -> f64 {
+    match state.check_winner() {
+        Player::AI => return cfg.victory_weight,
+        Player::Human => return -cfg.victory_weight,
+        Player::None => {}
+    }
+
+    let connection = (bounded_connection_distance(state, Player::Human) - bounded_connection_distance(state, Player::AI)) as f64;
+    let bridge = bridge_score(state, Player::AI) - bridge_score(state, Player::Human);
+    let centrality = centrality_score(state, Player::AI) - centrality_score(state, Player::Human);
+    let mobility = mobility_score(state, Player::AI) - mobility_score(state, Player::Human);
+
+    cfg.connection_weight * connection
+        + cfg.bridge_weight * bridge
+        + cfg.centrality_weight * centrality
+        + cfg.mobility_weight * mobility
+}
+
+/// Candidate moves for `player`, restricted to cells adjacent to an existing
+/// stone (the empty board has no such cells, so every move is legal then)
+/// and ordered by bridge potential plus centrality so alpha-beta prunes well.
+fn candidate_moves(state: &GameState, player: Player) -> Vec<usize> {
+    if state.turn_count == 0 {
+        return state.empty_cells.clone();
+    }
+
+    let mut candidates: Vec<usize> = state
+        .empty_cells
+        .iter()
+        .copied()
+        .filter(|&idx| GameState::get_neighbors(idx).iter().any(|&n| state.board[n] != Player::None))
+        .collect();
+
+    if candidates.is_empty() {
+        candidates = state.empty_cells.clone();
+    }
+
+    let center_r = BOARD_ROWS as i32 / 2;
+    let center_c = BOARD_COLS as i32 / 2;
+    candidates.sort_by_cached_key(|&idx| {
+        let bridge = state.evaluate_bridge_potential(idx, player);
+        let r = (idx / BOARD_COLS) as i32;
+        let c = (idx % BOARD_COLS) as i32;
+        let centrality = -((r - center_r).abs() + (c - center_c).abs());
+        std::cmp::Reverse(bridge + centrality)
+    });
+
+    candidates
+}
+
+/// Depth-limited minimax alpha-beta search; scores are always from the AI's
+/// point of view (AI maximizes, Human minimizes). `state` is taken by value
+/// since `GameState` has no `undo_move` - each ply clones rather than
+/// mutating and backtracking, same as `MCTSEngine::search`'s playouts.
+fn minimax(mut state: GameState, depth: u32, mut alpha: f64, mut beta: f64, to_move: Player, cfg: &ScoreConfig) -> f64 {
+    let winner = state.check_winner();
+    if winner != Player::None || depth == 0 || state.empty_cells.is_empty() {
+        return evaluate(&mut state, cfg);
+    }
+
+    let maximizing = to_move == Player::AI;
+    let mut best = if maximizing { f64::NEG_INFINITY } else { f64::INFINITY };
+
+    for m in candidate_moves(&state, to_move) {
+        let mut child = state.clone();
+        child.make_move(m, to_move);
+        let score = minimax(child, depth - 1, alpha, beta, to_move.opponent(), cfg);
+
+        if maximizing {
+            best = best.max(score);
+            alpha = alpha.max(best);
+        } else {
+            best = best.min(score);
+            beta = beta.min(best);
+        }
+        if alpha >= beta { break; }
+    }
+
+    best
+}
+
+/// Squashes a `minimax` score into the `[0, 1]` range `win_rate` uses
+/// elsewhere, saturating at a decisive (`±victory_weight`) result.
+fn normalize_score(score: f64, cfg: &ScoreConfig) -> f64 {
+    ((score / cfg.victory_weight) + 1.0).clamp(0.0, 2.0) / 2.0
+}
+
+fn find_best_move_minimax(state: &GameState, to_move: Player, depth: u32, cfg: &ScoreConfig) -> AnalysisResult {
+    let start = js_sys::Date::now();
+    let maximizing = to_move == Player::AI;
+
+    let mut alpha = f64::NEG_INFINITY;
+    let mut beta = f64::INFINITY;
+    let mut scored: Vec<(usize, f64)> = Vec::new();
+
+    for m in candidate_moves(state, to_move) {
+        let mut child = state.clone();
+        child.make_move(m, to_move);
+        let score = minimax(child, depth.saturating_sub(1), alpha, beta, to_move.opponent(), cfg);
+        scored.push((m, score));
+
+        if maximizing {
+            alpha = alpha.max(score);
+        } else {
+            beta = beta.min(score);
+        }
+    }
+
+    scored.sort_by(|a, b| {
+        if maximizing {
+            b.1.partial_cmp(&a.1).unwrap()
+        } else {
+            a.1.partial_cmp(&b.1).unwrap()
+        }
+    });
+
+    let to_move_info = |&(idx, score): &(usize, f64)| MoveInfo {
+        r: idx / BOARD_COLS,
+        c: idx % BOARD_COLS,
+        visits: 0,
+        wins: 0,
+        win_rate: normalize_score(score, cfg),
+    };
+
+    let best_move = scored.first().map(to_move_info);
+    let alternatives = scored.iter().take(5).map(to_move_info).collect();
+    let elapsed = js_sys::Date::now() - start;
+
+    AnalysisResult {
+        best_move,
+        alternatives,
+        total_simulations: scored.len() as u32,
+        elapsed_ms: elapsed,
+        nps: if elapsed > 0.0 { (scored.len() as f64) / (elapsed / 1000.0) } else { 0.0 },
+    }
+}
+
+/// Selects a move from merged per-move `(visits, wins)` totals using the
+/// same policy `MCTSEngine::search` applies to a single tree's root
+/// children: softmax over the top 5 by visits when `selection_temperature`
+/// is set, otherwise the most-visited move outright. `children` must
+/// already be sorted by visits descending.
+fn select_merged_move(children: &[(usize, f64, f64)], config: EngineConfig) -> Option<MoveInfo> {
+    let to_info = |&(m, visits, wins): &(usize, f64, f64)| MoveInfo {
+        r: m / BOARD_COLS,
+        c: m % BOARD_COLS,
+        visits: visits as u32,
+        wins: wins as u32,
+        win_rate: if visits > 0.0 { wins / visits } else { 0.0 },
+    };
+
+    if children.is_empty() {
+        return None;
+    }
+
+    if config.selection_temperature > 0.0 {
+        let mut rng = rand::thread_rng();
+        let limit = std::cmp::min(5, children.len());
+        let weights: Vec<f64> = children[..limit]
+            .iter()
+            .map(|&(_, visits, _)| visits.powf(1.0 / config.selection_temperature))
+            .collect();
+        let sum_weight: f64 = weights.iter().sum();
+
+        let mut r = rng.gen::<f64>() * sum_weight;
+        for (i, &w) in weights.iter().enumerate() {
+            r -= w;
+            if r <= 0.0 {
+                return Some(to_info(&children[i]));
+            }
+        }
+        Some(to_info(&children[0]))
+    } else {
+        children.first().map(to_info)
+    }
+}
+
+/// Runs `threads` independent MCTS searches from the same `root_state` and
+/// merges them by summing each root move's `visits`/`wins` across trees
+/// before the final selection policy runs - the "root parallelization"
+/// approach: N full trees instead of one, reconciled only at the end.
+///
+/// WASM here has no real OS threads to hand these to - that needs a
+/// `wasm-bindgen-rayon`-style worker pool wired in at the build level, which
+/// this crate snapshot doesn't set up - so the trees are still built one
+/// after another. The merge step below is the part that doesn't change once
+/// real concurrency lands; only this loop would need to become a
+/// `parallel`/worker dispatch.
+fn search_root_parallel(root_state: &GameState, player: Player, config: EngineConfig, time_limit_ms: u32, threads: u32) -> AnalysisResult {
+    let threads = threads.max(1);
+    let start = js_sys::Date::now();
+
+    let mut merged: std::collections::HashMap<usize, (f64, f64)> = std::collections::HashMap::new();
+    let mut total_simulations = 0u32;
+
+    for _ in 0..threads {
+        let mut engine = MCTSEngine::new(root_state.clone(), player, config);
+        let result = engine.search(time_limit_ms);
+        total_simulations += result.total_simulations;
+
+        for &child_idx in &engine.nodes[0].children {
+            let node = &engine.nodes[child_idx];
+            if let Some(m) = node.move_idx {
+                let entry = merged.entry(m).or_insert((0.0, 0.0));
+                entry.0 += node.visits;
+                entry.1 += node.wins;
+            }
+        }
+    }
+
+    let mut children: Vec<(usize, f64, f64)> = merged
+        .into_iter()
+        .map(|(m, (visits, wins))| (m, visits, wins))
+        .collect();
+    children.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+
+    let best_move = select_merged_move(&children, config);
+    let alternatives = children
+        .iter()
+        .take(5)
+        .map(|&(m, visits, wins)| MoveInfo {
+            r: m / BOARD_COLS,
+            c: m % BOARD_COLS,
+            visits: visits as u32,
+            wins: wins as u32,
+            win_rate: if visits > 0.0 { wins / visits } else { 0.0 },
+        })
+        .collect();
+
+    let elapsed = js_sys::Date::now() - start;
+    AnalysisResult {
+        best_move,
+        alternatives,
+        total_simulations,
+        elapsed_ms: elapsed,
+        nps: if elapsed > 0.0 { (total_simulations as f64) / (elapsed / 1000.0) } else { 0.0 },
+    }
+}
+
 #[wasm_bindgen]
-pub struct EntropyWasmEngine {}
+pub struct EntropyWasmEngine {
+    /// Persists across `get_best_move` calls so consecutive turns can
+    /// re-root onto the moves actually played since (see
+    /// `MCTSEngine::reroot_from_board`) instead of throwing away every
+    /// simulation and rebuilding the tree from scratch each time. Only the
+    /// MCTS backend uses this - a `minimax` call leaves it untouched so
+    /// switching strategies between turns doesn't lose the tree.
+    tree: Option<MCTSEngine>,
+}
 
 #[wasm_bindgen]
 impl EntropyWasmEngine {
     #[wasm_bindgen(constructor)]
-    pub fn new() -> Self { Self {} }
+    pub fn new() -> Self { Self { tree: None } }
 
-    pub fn get_best_move(&self, board_array: &[u8], is_ai_turn: bool, time_limit_ms: u32, difficulty_level: u32) -> Result<JsValue, JsValue> {
+    /// `strategy` selects the search backend: `"minimax"` for the
+    /// deterministic alpha-beta engine, anything else (including the
+    /// default `"mcts"`) for the persistent MCTS tree. `threads` (1 when the
+    /// client has no `SharedArrayBuffer`) switches MCTS into root-parallel
+    /// mode, running that many independent trees and merging their root
+    /// move statistics - see `search_root_parallel`. Root-parallel mode
+    /// can't be re-rooted the way a single persistent tree can, so it
+    /// doesn't touch `self.tree`.
+    pub fn get_best_move(&mut self, board_array: &[u8], is_ai_turn: bool, time_limit_ms: u32, difficulty_level: u32, strategy: &str, threads: u32) -> Result<JsValue, JsValue> {
         if board_array.len() != NUM_CELLS { return Err(JsValue::from_str("Invalid board size")); }
-        
+
         let mut state = GameState::new();
         for (i, &val) in board_array.iter().enumerate() {
-            if val == 1 { state.make_move(i, Player::Human); } 
+            if val == 1 { state.make_move(i, Player::Human); }
             else if val == 2 { state.make_move(i, Player::AI); }
         }
-        
+
         let player = if is_ai_turn { Player::AI } else { Player::Human };
-        let config = EngineConfig::for_difficulty(difficulty_level);
-        
-        let mut engine = MCTSEngine::new(state, player, config);
-        let result = engine.search(time_limit_ms);
-        
+
+        let result = match strategy {
+            "minimax" => {
+                let depth = minimax_depth_for_difficulty(difficulty_level);
+                find_best_move_minimax(&state, player, depth, &ScoreConfig::default_weights())
+            }
+            _ if threads > 1 => {
+                let config = EngineConfig::for_difficulty(difficulty_level);
+                search_root_parallel(&state, player, config, time_limit_ms, threads)
+            }
+            _ => {
+                let config = EngineConfig::for_difficulty(difficulty_level);
+                let mut engine = self.tree
+                    .take()
+                    .and_then(|prev| prev.reroot_from_board(&state, config))
+                    .unwrap_or_else(|| MCTSEngine::new(state, player, config));
+
+                let result = engine.search(time_limit_ms);
+                self.tree = Some(engine);
+                result
+            }
+        };
+
         Ok(serde_wasm_bindgen::to_value(&result)?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a two-ply tree by hand rather than through `expand`/`search`
+    /// (both pull from `rand`/`js_sys::Date`, neither of which this needs):
+    /// root has two children for AI's first move (5 and 7), and the move-5
+    /// child has its own child for Human's reply (6) - the line a real game
+    /// actually reached.
+    fn two_ply_tree() -> MCTSEngine {
+        let root_state = GameState::new();
+        let mut engine = MCTSEngine::new(root_state, Player::AI, EngineConfig::for_difficulty(3));
+
+        engine.nodes.push(MCTSNode {
+            move_idx: Some(5),
+            parent: Some(0),
+            children: vec![],
+            wins: 3.0,
+            visits: 10.0,
+            rave_wins: 0.0,
+            rave_visits: 0.0,
+            untried_moves: vec![],
+            player: Player::AI,
+        });
+        engine.nodes.push(MCTSNode {
+            move_idx: Some(7),
+            parent: Some(0),
+            children: vec![],
+            wins: 1.0,
+            visits: 4.0,
+            rave_wins: 0.0,
+            rave_visits: 0.0,
+            untried_moves: vec![],
+            player: Player::AI,
+        });
+        engine.nodes[0].children = vec![1, 2];
+
+        engine.nodes.push(MCTSNode {
+            move_idx: Some(6),
+            parent: Some(1),
+            children: vec![],
+            wins: 2.0,
+            visits: 5.0,
+            rave_wins: 0.0,
+            rave_visits: 0.0,
+            untried_moves: vec![],
+            player: Player::Human,
+        });
+        engine.nodes[1].children = vec![3];
+
+        engine
+    }
+
+    #[test]
+    fn reroot_descends_to_the_matching_child_and_discards_siblings() {
+        let engine = two_ply_tree();
+
+        let mut new_state = GameState::new();
+        new_state.make_move(5, Player::AI);
+        new_state.make_move(6, Player::Human);
+
+        let rerooted = engine
+            .reroot(&new_state, &[5, 6], EngineConfig::for_difficulty(3))
+            .expect("both moves exist in the tree");
+
+        // Compacted to a fresh vec rooted at what was node 3; sibling move 7
+        // (and its whole subtree) doesn't survive the reroot.
+        assert_eq!(rerooted.nodes.len(), 1);
+        assert_eq!(rerooted.nodes[0].move_idx, None);
+        assert_eq!(rerooted.nodes[0].visits, 5.0);
+        assert_eq!(rerooted.nodes[0].wins, 2.0);
+    }
+
+    #[test]
+    fn reroot_returns_none_for_a_move_the_tree_never_expanded() {
+        let engine = two_ply_tree();
+        let new_state = GameState::new();
+
+        assert!(engine.reroot(&new_state, &[5, 99], EngineConfig::for_difficulty(3)).is_none());
+    }
+
+    #[test]
+    fn reroot_from_board_replays_the_two_moves_played_since_the_tree_was_built() {
+        let engine = two_ply_tree();
+
+        let mut new_state = GameState::new();
+        new_state.make_move(5, Player::AI);
+        new_state.make_move(6, Player::Human);
+
+        let rerooted = engine
+            .reroot_from_board(&new_state, EngineConfig::for_difficulty(3))
+            .expect("diff is exactly the AI move then the Human reply");
+
+        assert_eq!(rerooted.nodes.len(), 1);
+        assert_eq!(rerooted.nodes[0].visits, 5.0);
+    }
+
+    #[test]
+    fn reroot_from_board_returns_none_when_more_than_two_cells_changed() {
+        let engine = two_ply_tree();
+
+        let mut divergent_state = GameState::new();
+        divergent_state.make_move(0, Player::AI);
+        divergent_state.make_move(1, Player::Human);
+        divergent_state.make_move(2, Player::AI);
+
+        assert!(engine
+            .reroot_from_board(&divergent_state, EngineConfig::for_difficulty(3))
+            .is_none());
+    }
+
+    #[test]
+    fn minimax_scores_an_immediate_win_at_the_victory_weight() {
+        let mut state = GameState::new();
+        let col = 5;
+        for r in 0..BOARD_ROWS - 1 {
+            state.make_move(r * BOARD_COLS + col, Player::AI);
+        }
+        let cfg = ScoreConfig::default_weights();
+
+        let mut winning_child = state.clone();
+        winning_child.make_move((BOARD_ROWS - 1) * BOARD_COLS + col, Player::AI);
+        let winning_score = minimax(winning_child, 1, f64::NEG_INFINITY, f64::INFINITY, Player::Human, &cfg);
+        assert_eq!(winning_score, cfg.victory_weight);
+
+        // A stone elsewhere on the last row doesn't complete the column's
+        // top-to-bottom chain, so it shouldn't score as a win.
+        let mut other_child = state.clone();
+        other_child.make_move((BOARD_ROWS - 1) * BOARD_COLS + 0, Player::AI);
+        let other_score = minimax(other_child, 1, f64::NEG_INFINITY, f64::INFINITY, Player::Human, &cfg);
+        assert!(other_score < winning_score);
+    }
+
+    #[test]
+    fn candidate_moves_on_an_empty_board_is_every_cell() {
+        let state = GameState::new();
+        assert_eq!(candidate_moves(&state, Player::AI).len(), NUM_CELLS);
+    }
+
+    #[test]
+    fn candidate_moves_after_the_opening_is_restricted_to_cells_near_a_stone() {
+        let mut state = GameState::new();
+        let center = (BOARD_ROWS / 2) * BOARD_COLS + BOARD_COLS / 2;
+        state.make_move(center, Player::AI);
+
+        let candidates = candidate_moves(&state, Player::Human);
+        assert!(candidates.len() < NUM_CELLS);
+        assert!(candidates
+            .iter()
+            .all(|&idx| GameState::get_neighbors(idx).iter().any(|&n| n == center)));
+    }
+
+    #[test]
+    fn connection_distance_on_an_empty_board_is_one_step_per_row_or_column() {
+        let state = GameState::new();
+        // A straight vertical (for AI) or horizontal (for Human) line at a
+        // fixed column/row is always a chain of neighbors, regardless of
+        // row parity - see `get_neighbors`'s (1,0)/(0,1) offsets, which both
+        // parities share. Every cell along it costs 1 on an empty board, so
+        // the cheapest path costs exactly one board dimension's worth of
+        // cells.
+        assert_eq!(state.connection_distance(Player::AI), BOARD_ROWS as i32);
+        assert_eq!(state.connection_distance(Player::Human), BOARD_COLS as i32);
+    }
+
+    #[test]
+    fn connection_distance_is_zero_once_the_edges_are_already_connected() {
+        let mut state = GameState::new();
+        let col = 3;
+        for r in 0..BOARD_ROWS {
+            state.make_move(r * BOARD_COLS + col, Player::AI);
+        }
+        assert_eq!(state.connection_distance(Player::AI), 0);
+    }
+
+    #[test]
+    fn connection_distance_is_unreachable_when_the_near_edge_is_fully_blocked() {
+        let mut state = GameState::new();
+        for c in 0..BOARD_COLS {
+            state.make_move(c, Player::Human);
+        }
+        assert_eq!(state.connection_distance(Player::AI), UNREACHABLE_DISTANCE);
+    }
+
+    #[test]
+    fn select_merged_move_without_temperature_picks_the_most_visited_move() {
+        // `children` must already be visits-descending, as `search_root_parallel`
+        // sorts it before calling this.
+        let children = [(20usize, 80.0, 10.0), (10usize, 50.0, 30.0)];
+        let config = EngineConfig { max_simulations: 0, playout_heuristic_chance: 0.0, selection_temperature: 0.0 };
+
+        let best = select_merged_move(&children, config).expect("non-empty children");
+        assert_eq!((best.r, best.c), (20 / BOARD_COLS, 20 % BOARD_COLS));
+        assert_eq!(best.visits, 80);
+        assert_eq!(best.wins, 10);
+    }
+
+    #[test]
+    fn select_merged_move_on_empty_children_is_none() {
+        let config = EngineConfig::for_difficulty(3);
+        assert!(select_merged_move(&[], config).is_none());
+    }
+
+    /// (4,4) and (3,5) are a bridge pair: both are two hops apart and share
+    /// exactly the two empty neighbors (3,4) and (4,5) as carriers.
+    fn place_bridge(state: &mut GameState, player: Player) -> (usize, usize, usize, usize) {
+        let anchor = 4 * BOARD_COLS + 4;
+        let partner = 3 * BOARD_COLS + 5;
+        let carrier_a = 3 * BOARD_COLS + 4;
+        let carrier_b = 4 * BOARD_COLS + 5;
+        state.make_move(anchor, player);
+        state.make_move(partner, player);
+        (anchor, partner, carrier_a, carrier_b)
+    }
+
+    #[test]
+    fn bridge_carriers_finds_the_two_connecting_cells() {
+        let mut state = GameState::new();
+        let (anchor, _partner, carrier_a, carrier_b) = place_bridge(&mut state, Player::AI);
+
+        let carriers = state.bridge_carriers(anchor);
+        assert!(carriers
+            .iter()
+            .any(|&(a, b)| (a, b) == (carrier_a, carrier_b) || (a, b) == (carrier_b, carrier_a)));
+    }
+
+    #[test]
+    fn bridge_carriers_is_empty_on_an_empty_cell() {
+        let state = GameState::new();
+        assert!(state.bridge_carriers(4 * BOARD_COLS + 4).is_empty());
+    }
+
+    #[test]
+    fn find_save_bridge_recaptures_after_the_opponent_takes_one_carrier() {
+        let mut state = GameState::new();
+        let (_anchor, _partner, carrier_a, carrier_b) = place_bridge(&mut state, Player::AI);
+        state.make_move(carrier_a, Player::Human);
+
+        assert_eq!(find_save_bridge(&state, Player::AI, carrier_a), Some(carrier_b));
+    }
+
+    #[test]
+    fn find_save_bridge_is_none_when_the_opponent_plays_elsewhere() {
+        let mut state = GameState::new();
+        place_bridge(&mut state, Player::AI);
+        let unrelated = BOARD_COLS * BOARD_COLS - 1;
+        state.make_move(unrelated, Player::Human);
+
+        assert_eq!(find_save_bridge(&state, Player::AI, unrelated), None);
+    }
+}
@@ -8,10 +8,6 @@
 pub const BOARD_SIZE: u8 = 7;
 pub const CELL_COUNT: u8 = 49;
 
-// Precomputed tables (to be filled or hardcoded)
-// For now, we calculate them on the fly or use macros if possible.
-// In a full implementation, we'd use `lazy_static` or `const` generated arrays.
-
 pub fn pos_to_mask(r: u8, c: u8) -> u64 {
     if r >= BOARD_SIZE || c >= BOARD_SIZE {
         return 0;
@@ -46,77 +42,178 @@ pub const MASK_COL_6: u64 = get_col_6_mask();
 pub const NOT_COL_0: u64 = !MASK_COL_0;
 pub const NOT_COL_6: u64 = !MASK_COL_6;
 
-/// Expand a bitboard in all 8 queen directions simultaneously (bit-parallel)
-/// This is MUCH faster than iterating over individual bits.
+/// Signed shift: positive shifts left, negative shifts right. The eight
+/// queen directions below are expressed as signed offsets into the 7x7
+/// bitboard (`+7`/`-7` for N/S, `+1`/`-1` for E/W, `+8`/`-6` and `+6`/`-8`
+/// for the diagonals) so one function covers both shift directions.
+fn shift_signed(bb: u64, s: i32) -> u64 {
+    if s >= 0 { bb << s } else { bb >> (-s) }
+}
+
+/// One direction's occluded fill via Kogge-Stone doubling: three rounds of
+/// `gen |= pro & (gen << s); pro &= (pro << s)` (doubling `s` each round)
+/// cover up to 7 steps on the 7-wide board in ~3 dependent shifts instead of
+/// 6, then a final unconditional shift peels off the last reachable square.
+/// `wrap_mask` is ANDed into `pro` up front (and so implicitly re-applied
+/// every round as `pro` keeps shifting by itself) so the fill can never
+/// bleed across the row boundary the way a raw `u64` shift would.
+fn kogge_stone_fill(source: u64, empty: u64, s: i32, wrap_mask: u64) -> u64 {
+    let mut gen = source;
+    let mut pro = empty & wrap_mask;
+
+    let mut step = s;
+    for _ in 0..3 {
+        gen |= pro & shift_signed(gen, step);
+        pro &= shift_signed(pro, step);
+        step *= 2;
+    }
+
+    shift_signed(gen, s) & wrap_mask & empty
+}
+
+/// (shift, anti-wrap mask) per direction, in N/S/E/W/NE/NW/SE/SW order -
+/// matching `RAY_DELTAS` below so a direction index means the same thing
+/// across both the shift-based and delta-walk APIs. North/South only move
+/// between rows so they need no column mask; the diagonals and E/W reuse
+/// the same column masks the old per-step loops checked the result against.
+const QUEEN_DIRECTIONS: [(i32, u64); 8] = [
+    (7, !0),          // North
+    (-7, !0),         // South
+    (1, NOT_COL_0),   // East
+    (-1, NOT_COL_6),  // West
+    (8, NOT_COL_0),   // NE
+    (6, NOT_COL_6),   // NW
+    (-6, NOT_COL_0),  // SE
+    (-8, NOT_COL_6),  // SW
+];
+
+/// Expand a bitboard in all 8 queen directions simultaneously (bit-parallel).
+/// Each direction is a Kogge-Stone occluded fill (see `kogge_stone_fill`):
+/// three doubling rounds plus a final step, instead of up to six serial
+/// shift-and-mask iterations - this is the fill-based sliding-attack
+/// technique used for rook/bishop move generation in bitboard chess engines.
 pub fn expand_queen_bit_parallel(source: u64, blocked: u64) -> u64 {
-    let mut expanded = 0u64;
     let empty = !blocked;
 
-    // North: << 7
-    let mut fill = source;
-    for _ in 0..6 {
-        fill = (fill << 7) & empty;
-        if fill == 0 { break; }
-        expanded |= fill;
+    let mut expanded = 0u64;
+    for &(s, wrap_mask) in &QUEEN_DIRECTIONS {
+        expanded |= kogge_stone_fill(source, empty, s, wrap_mask);
     }
+    expanded
+}
 
-    // South: >> 7
-    let mut fill = source;
-    for _ in 0..6 {
-        fill = (fill >> 7) & empty;
-        if fill == 0 { break; }
-        expanded |= fill;
-    }
+/// Same expansion as `expand_queen_bit_parallel`, but broken out per
+/// direction instead of OR'd together - lets a caller (e.g. Voronoi path
+/// tracking) know which of the 8 directions newly-reached cells came from.
+pub fn expand_queen_per_direction(source: u64, blocked: u64) -> [u64; 8] {
+    let empty = !blocked;
 
-    // East: << 1 (avoiding wrap from Col 6 to Col 0)
-    let mut fill = source;
-    for _ in 0..6 {
-        fill = (fill << 1) & NOT_COL_0 & empty;
-        if fill == 0 { break; }
-        expanded |= fill;
+    let mut per_direction = [0u64; 8];
+    for (i, &(s, wrap_mask)) in QUEEN_DIRECTIONS.iter().enumerate() {
+        per_direction[i] = kogge_stone_fill(source, empty, s, wrap_mask);
     }
+    per_direction
+}
 
-    // West: >> 1 (avoiding wrap from Col 0 to Col 6)
-    let mut fill = source;
-    for _ in 0..6 {
-        fill = (fill >> 1) & NOT_COL_6 & empty;
-        if fill == 0 { break; }
-        expanded |= fill;
-    }
+/// Row/col deltas for the same 8 directions `expand_queen_bit_parallel` uses,
+/// in the same N/S/E/W/NE/NW/SE/SW order, so a direction index means the
+/// same thing across both APIs.
+const RAY_DELTAS: [(i8, i8); 8] = [
+    (1, 0), (-1, 0), (0, 1), (0, -1),
+    (1, 1), (1, -1), (-1, 1), (-1, -1),
+];
+
+struct RayTables {
+    /// Per-square occupancy-free queen reach (all 8 rays OR'd together).
+    pseudo_attacks: [u64; 49],
+    /// `between[a][b]`: squares strictly between `a` and `b` if queen-aligned, else 0.
+    between: [[u64; 49]; 49],
+    /// `rays[dir][idx]`: the full ray from `idx` in direction `dir`, ignoring occupancy.
+    rays: [[u64; 49]; 8],
+}
 
-    // NE: << 8 (avoiding wrap from Col 6 to Col 0)
-    let mut fill = source;
-    for _ in 0..6 {
-        fill = (fill << 8) & NOT_COL_0 & empty;
-        if fill == 0 { break; }
-        expanded |= fill;
-    }
+fn build_ray_tables() -> RayTables {
+    let mut pseudo_attacks = [0u64; 49];
+    let mut between = [[0u64; 49]; 49];
+    let mut rays = [[0u64; 49]; 8];
 
-    // NW: << 6 (avoiding wrap from Col 0 to Col 6)
-    let mut fill = source;
-    for _ in 0..6 {
-        fill = (fill << 6) & NOT_COL_6 & empty;
-        if fill == 0 { break; }
-        expanded |= fill;
-    }
+    for idx in 0..49u8 {
+        let (r, c) = index_to_pos(idx);
+
+        for (dir, &(dr, dc)) in RAY_DELTAS.iter().enumerate() {
+            let mut ray_mask = 0u64;
+            let mut acc = 0u64;
+            let (mut rr, mut cc) = (r as i8 + dr, c as i8 + dc);
+
+            while rr >= 0 && rr < BOARD_SIZE as i8 && cc >= 0 && cc < BOARD_SIZE as i8 {
+                let sq = pos_to_index(rr as u8, cc as u8);
+                between[idx as usize][sq as usize] = acc;
+                between[sq as usize][idx as usize] = acc;
+
+                ray_mask |= pos_to_mask(rr as u8, cc as u8);
+                acc |= pos_to_mask(rr as u8, cc as u8);
 
-    // SE: >> 6 (avoiding wrap from Col 6 to Col 0)
-    let mut fill = source;
-    for _ in 0..6 {
-        fill = (fill >> 6) & NOT_COL_0 & empty;
-        if fill == 0 { break; }
-        expanded |= fill;
+                rr += dr;
+                cc += dc;
+            }
+
+            rays[dir][idx as usize] = ray_mask;
+            pseudo_attacks[idx as usize] |= ray_mask;
+        }
     }
 
-    // SW: >> 8 (avoiding wrap from Col 0 to Col 6)
-    let mut fill = source;
-    for _ in 0..6 {
-        fill = (fill >> 8) & NOT_COL_6 & empty;
-        if fill == 0 { break; }
-        expanded |= fill;
+    RayTables { pseudo_attacks, between, rays }
+}
+
+static RAY_TABLES: std::sync::OnceLock<RayTables> = std::sync::OnceLock::new();
+
+fn ray_tables() -> &'static RayTables {
+    RAY_TABLES.get_or_init(build_ray_tables)
+}
+
+/// Occupancy-free queen reach from `idx`: every square reachable by a queen
+/// slide in any of the 8 directions, as if the rest of the board were empty.
+/// Stockfish-style `PseudoAttacks` table, built once and cached.
+pub fn queen_pseudo_attacks(idx: u8) -> u64 {
+    ray_tables().pseudo_attacks[idx as usize]
+}
+
+/// Squares strictly between `a` and `b`, if they lie on a common queen ray
+/// (same row, column, or diagonal); `0` if they don't align or are adjacent.
+pub fn between(a: u8, b: u8) -> u64 {
+    ray_tables().between[a as usize][b as usize]
+}
+
+/// The full ray from `idx` in direction `dir` (see `RAY_DELTAS` for the
+/// N/S/E/W/NE/NW/SE/SW ordering), ignoring occupancy.
+pub fn ray(idx: u8, dir: usize) -> u64 {
+    ray_tables().rays[dir][idx as usize]
+}
+
+/// Direction index pointing the opposite way from `dir` (see `RAY_DELTAS`).
+fn reverse_dir(dir: usize) -> usize {
+    match dir {
+        0 => 1, 1 => 0,
+        2 => 3, 3 => 2,
+        4 => 7, 7 => 4,
+        5 => 6, 6 => 5,
+        _ => unreachable!("direction index out of range: {dir}"),
     }
+}
 
-    expanded
+/// The queen line running through `a` and `b`: the segment strictly between
+/// them (`between`) plus everything beyond each endpoint in the same
+/// direction. `0` if the two squares don't share a row, column, or
+/// diagonal. Wider than `between` alone - catches cut cells that lie just
+/// past one of the pieces rather than only in the segment connecting them.
+pub fn aligned_line(a: u8, b: u8) -> u64 {
+    for dir in 0..8 {
+        if ray(a, dir) & (1u64 << b) != 0 {
+            let rev = reverse_dir(dir);
+            return between(a, b) | ray(b, dir) | ray(a, rev);
+        }
+    }
+    0
 }
 
 pub fn count_ones(bitboard: u64) -> u32 {
@@ -130,62 +227,6 @@ pub fn get_queen_moves(r: u8, c: u8, blocked: u64) -> u64 {
     expand_queen_bit_parallel(source, blocked)
 }
 
-/// Simple floodfill to determine reachable area size
-pub fn flood_fill(start: u64, blocked: u64) -> u64 {
-    let mut flood = start;
-    let mut frontier = start;
-    
-    // Iteratively expand until no new cells are found
-    // A Queen move can reach anywhere in line of sight, but here we treat connectivity
-    // "Reachable" means connected via queen moves.
-    // Standard flood fill uses adjacent connectivity. 
-    // BUT Isolation is Queen move.
-    // However, for "Partition" detection, adjacent connectivity is sufficient?
-    // Actually no, Queen can jump gaps? No, Queen slides.
-    // If we want "Connected Component", standard 8-way adjacency is correct.
-    
-    // Standard 8-way dilation
-    while frontier != 0 {
-        let mut new_frontier = 0;
-        
-        // This is slow O(N) iteration. In optimized bitboard we do shifts.
-        // For 7x7, shifts are: +/-1, +/-7, +/-6, +/-8
-        // Need to handle wrapping (overflow from col 6 to col 0 is bad for +/-1)
-        
-        // Horizontal dilation
-        let _not_col_a = 0xFEFEFEFEFEFEFEFEu64; // Mask to avoid wrapping left
-        let _not_col_h = 0x7F7F7F7F7F7F7F7Fu64; // Mask to avoid wrapping right (for 8x8 space)
-        
-        // But we are 7x7 mapped to u64. Custom shifts needed.
-        // Or just iterate set bits since count is low (<49).
-        
-        let mut temp = frontier;
-        while temp != 0 {
-            let _lsb = temp & (!temp + 1); // Extract lowest set bit (1 << ctz(temp)) is safer
-            let idx = temp.trailing_zeros() as u8;
-            let (r, c) = index_to_pos(idx);
-            
-            // Get all visible queen moves from here?
-            // No, "connected component" usually implies adjacency.
-            // In Isolation, two cells are connected if you can move between them.
-            // Since pieces slide, yes, adjacency is sufficient for reachability *if* space is open.
-            // So we use Queen moves.
-            
-            let moves = get_queen_moves(r, c, blocked);
-            let meaningful_moves = moves & !flood;
-            
-            new_frontier |= meaningful_moves;
-            flood |= meaningful_moves;
-            
-            temp &= temp - 1; // Clear LSB
-        }
-        
-        frontier = new_frontier;
-    }
-    
-    flood
-}
-
 /// Safely extract position index from a bitboard
 /// Returns None if bitboard is empty (0) or index is out of bounds
 pub fn safe_get_position_index(bitboard: u64) -> Option<u8> {
@@ -1,6 +1,7 @@
 
 use serde::{Serialize, Deserialize};
 use crate::bitboard::*;
+use crate::partition::{find_cut_cells, detect_partition_bitboard};
 
 #[derive(Clone, Copy, Debug)]
 pub struct GameState {
@@ -116,4 +117,76 @@ impl GameState {
 
         moves
     }
+
+    /// Moves whose destroy choice would isolate the opponent into a smaller
+    /// region — this engine's analogue of a "threat move": the search can
+    /// try these first, and grant a depth extension when the opponent's
+    /// best reply is itself one of these, mirroring the usual
+    /// create-a-threat/prevent-the-threat asymmetry. Unlike
+    /// `get_valid_moves`, each returned `Move` has a real `destroy` square
+    /// (a partition-relevant cut cell, per `find_cut_cells`) and `score` set
+    /// to the resulting region-size swing (mover's region minus opponent's).
+    pub fn partition_threats(&self, is_ai: bool) -> Vec<Move> {
+        let (my_pos_mask, opp_pos_mask) = if is_ai { (self.ai, self.player) } else { (self.player, self.ai) };
+        let blocked = self.destroyed | opp_pos_mask;
+
+        let my_idx = my_pos_mask.trailing_zeros() as u8;
+        let from = index_to_pos(my_idx);
+        let opp_idx = opp_pos_mask.trailing_zeros() as u8;
+        let opp_pos = index_to_pos(opp_idx);
+
+        let move_mask = get_queen_moves(from.0, from.1, blocked);
+        let full_board = (1u64 << CELL_COUNT) - 1;
+
+        let mut threats = Vec::new();
+        let mut targets = move_mask;
+        while targets != 0 {
+            let to_idx = targets.trailing_zeros() as u8;
+            targets &= targets - 1;
+            let to = index_to_pos(to_idx);
+            let to_mask = 1u64 << to_idx;
+
+            let empty = full_board & !(self.destroyed | opp_pos_mask | to_mask);
+
+            let cut_cells = if is_ai {
+                find_cut_cells(opp_pos, to, self.destroyed)
+            } else {
+                find_cut_cells(to, opp_pos, self.destroyed)
+            } & empty;
+
+            let mut candidates = cut_cells;
+            while candidates != 0 {
+                let d_idx = candidates.trailing_zeros() as u8;
+                candidates &= candidates - 1;
+                let destroy = index_to_pos(d_idx);
+                let destroyed_after = self.destroyed | (1u64 << d_idx);
+
+                let result = if is_ai {
+                    detect_partition_bitboard(opp_pos, to, destroyed_after)
+                } else {
+                    detect_partition_bitboard(to, opp_pos, destroyed_after)
+                };
+
+                if !result.is_partitioned {
+                    continue;
+                }
+
+                let (my_region, opp_region) = if is_ai {
+                    (result.ai_region_size, result.player_region_size)
+                } else {
+                    (result.player_region_size, result.ai_region_size)
+                };
+
+                threats.push(Move {
+                    from,
+                    to,
+                    destroy,
+                    score: my_region - opp_region,
+                });
+            }
+        }
+
+        threats
+    }
 }
+
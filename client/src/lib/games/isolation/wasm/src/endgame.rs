@@ -11,6 +11,8 @@
 use crate::board::{GameState, Move};
 use crate::bitboard::*;
 use crate::partition::*;
+use crate::transposition::{Bound, TranspositionTable};
+use std::collections::HashMap;
 
 /// Endgame solver result
 pub struct EndgameResult {
@@ -28,13 +30,26 @@ pub enum EndgameConfidence {
 
 /// Solve the endgame for an isolated position
 ///
-/// Uses iterative DFS to find the longest path from current position
+/// Uses iterative DFS to find the longest path from current position.
+/// `use_warnsdorff` selects Warnsdorff-ordered move exploration (fewest
+/// onward moves first, so a truncated search still finds a near-optimal
+/// path) over raw bit-index order; pass `false` only to benchmark against
+/// the unordered baseline.
 pub fn solve_endgame(
     state: &GameState,
     reachable_region: u64,
     is_ai: bool,
     time_limit_ms: u32,
+    use_warnsdorff: bool,
 ) -> EndgameResult {
+    // Full move-by-move DFS is only worth attempting on regions small enough
+    // to solve exactly; beyond that, a simulated-annealing local search
+    // produces a real, strong move instead of spending the whole budget
+    // only to time out.
+    if !should_solve_exactly(count_ones(reachable_region) as i32) {
+        return solve_endgame_annealed(state, reachable_region, is_ai, time_limit_ms);
+    }
+
     let start_time = js_sys::Date::now();
     let time_limit = time_limit_ms as f64;
 
@@ -50,6 +65,13 @@ pub fn solve_endgame(
     let mut best_path = -1;
     let mut solved = true;
 
+    // Shared across every candidate first move below: different opening
+    // moves often re-converge on the same (position, visited-set) state
+    // deep in their subtrees, so memoizing across the whole loop - not just
+    // within a single `longest_path_from_position` call - avoids redundant
+    // re-exploration of that shared tail.
+    let mut memo = PathMemo::new();
+
     for mv in moves {
         // Check timeout
         if js_sys::Date::now() - start_time > time_limit * 0.8 {
@@ -75,6 +97,8 @@ pub fn solve_endgame(
             state.destroyed,
             visited,
             remaining_time,
+            &mut memo,
+            use_warnsdorff,
         );
 
         let path_length = 1 + result.length;
@@ -114,13 +138,97 @@ struct PathResult {
     timed_out: bool,
 }
 
+/// Per-cell Zobrist keys for hashing `(current_pos_index, visited_bitboard)`
+/// states reached during longest-path DFS. Generated with the same
+/// fixed-seed LCG construction `TranspositionTable` uses for its own keys,
+/// kept private to path search since these states have nothing to do with
+/// the main search's position hash.
+struct PathZobrist {
+    cell: [u64; CELL_COUNT as usize],
+    current: [u64; CELL_COUNT as usize],
+}
+
+impl PathZobrist {
+    fn new() -> Self {
+        let mut seed = 0x9E3779B97F4A7C15u64;
+        let mut next_key = || {
+            seed = seed
+                .wrapping_mul(6364136223846793005u64)
+                .wrapping_add(1442695040888963407u64);
+            seed
+        };
+
+        let mut cell = [0u64; CELL_COUNT as usize];
+        let mut current = [0u64; CELL_COUNT as usize];
+        for i in 0..CELL_COUNT as usize {
+            cell[i] = next_key();
+            current[i] = next_key();
+        }
+
+        PathZobrist { cell, current }
+    }
+
+    fn hash(&self, pos_idx: u8, visited: u64) -> u64 {
+        let mut h = self.current[pos_idx as usize];
+        let mut v = visited;
+        while v != 0 {
+            let idx = v.trailing_zeros() as usize;
+            h ^= self.cell[idx];
+            v &= v - 1;
+        }
+        h
+    }
+}
+
+/// Transposition table for longest-path DFS, keyed on a Zobrist hash of
+/// `(current_pos_index, visited_bitboard)`. Different move orders within
+/// (and across) `longest_path_from_position` calls frequently re-arrive at
+/// the same state having covered the same ground, so caching the best
+/// suffix length already found from that state turns that re-exploration
+/// into a lookup.
+///
+/// An entry's `bool` is `true` when the stored length is only a lower
+/// bound - the subtree was abandoned before finishing, so the real answer
+/// may be longer - and `false` when the subtree fully completed and the
+/// length is exact. Callers must never treat a lower-bound hit as if it
+/// settled the state.
+struct PathMemo {
+    zobrist: PathZobrist,
+    entries: HashMap<u64, (i32, bool)>,
+}
+
+impl PathMemo {
+    fn new() -> Self {
+        PathMemo {
+            zobrist: PathZobrist::new(),
+            entries: HashMap::new(),
+        }
+    }
+
+    fn get(&self, pos_idx: u8, visited: u64) -> Option<(i32, bool)> {
+        self.entries.get(&self.zobrist.hash(pos_idx, visited)).copied()
+    }
+
+    fn insert(&mut self, pos_idx: u8, visited: u64, length: i32, is_lower_bound: bool) {
+        let key = self.zobrist.hash(pos_idx, visited);
+        self.entries.insert(key, (length, is_lower_bound));
+    }
+}
+
 /// Calculate longest path from a position using iterative DFS
+///
+/// `memo` is shared across every call made for the same underlying region
+/// (see `solve_endgame`'s loop over candidate first moves), so a state
+/// found deep under one candidate move can short-circuit the same state
+/// reached under another.
 fn longest_path_from_position(
     start_pos: (u8, u8),
     reachable: u64,
     blocked: u64,
     initial_visited: u64,
     time_limit_ms: u32,
+    memo: &mut PathMemo,
+    use_warnsdorff: bool,
 ) -> PathResult {
     let start_time = js_sys::Date::now();
     let time_limit = time_limit_ms as f64;
@@ -132,14 +240,30 @@ fn longest_path_from_position(
         next_move_idx: usize,
         moves: Vec<(u8, u8)>,
         path_length: i32,
+        /// Best number of additional moves found so far among this frame's
+        /// children; folded into the memo entry written when it backtracks.
+        best_suffix: i32,
+        /// Whether every contribution folded into `best_suffix` so far came
+        /// from a fully-explored child (not a branch-and-bound prune or a
+        /// lower-bound memo hit). Written into the memo entry on backtrack.
+        exact: bool,
+        /// Still-unvisited cells reachable from `pos` by repeated queen-move
+        /// flood fill, restricted to the parent's own reachable set (it can
+        /// only shrink on descent). An admissible upper bound on how many
+        /// more cells this subtree could possibly add.
+        reachable: u64,
     }
 
-    let start_idx = pos_to_index(start_pos.0, start_pos.1);
     let valid_cells = reachable;
     let move_blocked = blocked | !valid_cells;
 
     // Get initial moves
-    let initial_moves = get_moves_from_position(start_pos, move_blocked | initial_visited, valid_cells);
+    let initial_moves = get_moves_from_position(
+        start_pos,
+        move_blocked | initial_visited,
+        valid_cells,
+        use_warnsdorff,
+    );
 
     if initial_moves.is_empty() {
         return PathResult {
@@ -148,53 +272,129 @@ fn longest_path_from_position(
         };
     }
 
+    let root_reachable = queen_flood_fill(start_pos, move_blocked | initial_visited) & !initial_visited;
+
     let mut stack: Vec<StackFrame> = vec![StackFrame {
         pos: start_pos,
         visited: initial_visited,
         next_move_idx: 0,
         moves: initial_moves,
         path_length: 0,
+        best_suffix: 0,
+        exact: true,
+        reachable: root_reachable,
     }];
 
     let mut max_length = 0;
     let mut timed_out = false;
+    let mut used_lower_bound = false;
+
+    loop {
+        if stack.is_empty() {
+            break;
+        }
 
-    while let Some(frame) = stack.last_mut() {
         // Check timeout
         if js_sys::Date::now() - start_time > time_limit {
             timed_out = true;
             break;
         }
 
-        if frame.next_move_idx >= frame.moves.len() {
-            // Backtrack
-            max_length = max_length.max(frame.path_length);
-            stack.pop();
+        let (at_end, bounded_out) = {
+            let frame = stack.last().unwrap();
+            let at_end = frame.next_move_idx >= frame.moves.len();
+            // Admissible upper bound, A*-style: the remaining path can add
+            // at most as many cells as are even theoretically still
+            // reachable, so if that can't beat what's already been found
+            // there's no point expanding further.
+            let bounded_out =
+                frame.path_length + count_ones(frame.reachable) as i32 <= max_length;
+            (at_end, bounded_out)
+        };
+
+        if at_end || bounded_out {
+            let frame = stack.pop().unwrap();
+
+            if at_end {
+                // Fully explored: the best suffix found is final.
+                max_length = max_length.max(frame.path_length + frame.best_suffix);
+
+                let pos_idx = pos_to_index(frame.pos.0, frame.pos.1);
+                memo.insert(pos_idx, frame.visited, frame.best_suffix, !frame.exact);
+
+                if let Some(parent) = stack.last_mut() {
+                    parent.best_suffix = parent.best_suffix.max(1 + frame.best_suffix);
+                    parent.exact = parent.exact && frame.exact;
+                }
+            } else {
+                // Branch-and-bound prune: provably unable to beat the
+                // current best, so cutting it here never costs the true
+                // answer - but we didn't finish exploring it, so we don't
+                // actually know its own suffix length and can't memoize it.
+                // The parent's own eventual result is no longer provably
+                // exact, since this unexplored child might have been its
+                // true best among siblings.
+                if let Some(parent) = stack.last_mut() {
+                    parent.exact = false;
+                }
+            }
             continue;
         }
 
-        let next_pos = frame.moves[frame.next_move_idx];
-        frame.next_move_idx += 1;
+        let next_pos = {
+            let frame = stack.last_mut().unwrap();
+            let mv = frame.moves[frame.next_move_idx];
+            frame.next_move_idx += 1;
+            mv
+        };
 
         let next_idx = pos_to_index(next_pos.0, next_pos.1);
         let next_mask = 1u64 << next_idx;
 
-        // Skip if already visited
+        let frame = stack.last().unwrap();
         if (frame.visited & next_mask) != 0 {
             continue;
         }
 
-        // Make move
         let new_visited = frame.visited | next_mask;
         let new_path_length = frame.path_length + 1;
 
+        // Consult the memo before expanding further.
+        if let Some((cached_len, is_lower_bound)) = memo.get(next_idx, new_visited) {
+            max_length = max_length.max(new_path_length + cached_len);
+            used_lower_bound = used_lower_bound || is_lower_bound;
+
+            let frame = stack.last_mut().unwrap();
+            frame.best_suffix = frame.best_suffix.max(1 + cached_len);
+            frame.exact = frame.exact && !is_lower_bound;
+            continue;
+        }
+
         // Get next moves
-        let next_moves = get_moves_from_position(next_pos, move_blocked | new_visited, valid_cells);
+        let next_moves = get_moves_from_position(
+            next_pos,
+            move_blocked | new_visited,
+            valid_cells,
+            use_warnsdorff,
+        );
 
         if next_moves.is_empty() {
-            // Dead end - update max
+            // Dead end - exact leaf, worth memoizing too.
             max_length = max_length.max(new_path_length);
+            memo.insert(next_idx, new_visited, 0, false);
+
+            let frame = stack.last_mut().unwrap();
+            frame.best_suffix = frame.best_suffix.max(1);
         } else {
+            // Flood-filled reachability shrinks on descent: a cell this
+            // child can reach must already have been reachable from the
+            // parent, so flood-filling within the parent's own cached
+            // `reachable` set (rather than the whole region) is both
+            // correct and cheaper.
+            let domain = frame.reachable;
+            let child_reachable =
+                queen_flood_fill(next_pos, !domain | new_visited) & domain & !new_visited;
+
             // Push new frame
             stack.push(StackFrame {
                 pos: next_pos,
@@ -202,21 +402,34 @@ fn longest_path_from_position(
                 next_move_idx: 0,
                 moves: next_moves,
                 path_length: new_path_length,
+                best_suffix: 0,
+                exact: true,
+                reachable: child_reachable,
             });
         }
     }
 
+    // Anything still open on the stack was abandoned before finishing, so
+    // whatever suffix it found so far is only a lower bound - flush it into
+    // the memo as such rather than dropping that partial work on the floor.
+    while let Some(frame) = stack.pop() {
+        let pos_idx = pos_to_index(frame.pos.0, frame.pos.1);
+        memo.insert(pos_idx, frame.visited, frame.best_suffix, true);
+    }
+
     PathResult {
         length: max_length,
-        timed_out,
+        timed_out: timed_out || used_lower_bound,
     }
 }
 
-/// Get moves from a position as a Vec
+/// Get moves from a position as a Vec, in bit-index order unless
+/// `ordered` requests Warnsdorff ordering instead.
 fn get_moves_from_position(
     pos: (u8, u8),
     blocked: u64,
     valid_cells: u64,
+    ordered: bool,
 ) -> Vec<(u8, u8)> {
     let moves_bb = get_queen_moves(pos.0, pos.1, blocked);
     let valid_moves = moves_bb & valid_cells;
@@ -230,9 +443,25 @@ fn get_moves_from_position(
         temp &= temp - 1;
     }
 
+    if ordered {
+        order_by_warnsdorff(&mut moves, blocked, valid_cells);
+    }
+
     moves
 }
 
+/// Warnsdorff's rule: sort candidate destinations by ascending onward
+/// degree - the number of legal queen moves still available from each one
+/// once it's marked visited. Exploring low-mobility cells first tends to
+/// avoid stranding dead-end corners for later, so even a time-limited DFS
+/// finds a long path early instead of one that's essentially arbitrary.
+fn order_by_warnsdorff(moves: &mut [(u8, u8)], blocked: u64, valid_cells: u64) {
+    moves.sort_by_cached_key(|&(r, c)| {
+        let after_move = blocked | pos_to_mask(r, c);
+        count_ones(get_queen_moves(r, c, after_move) & valid_cells & !after_move)
+    });
+}
+
 /// Find the best destroy position in endgame
 ///
 /// Prioritizes destroying cells outside our reachable region
@@ -288,6 +517,118 @@ fn find_best_endgame_destroy(
     best_destroy
 }
 
+/// True maximum number of move-and-destroy turns a lone queen starting at
+/// `start_pos` can make while confined to `region_mask`. Unlike the region's
+/// raw cell count, this accounts for the queen not necessarily being able
+/// to visit every cell in the region before it runs out of moves.
+///
+/// Memoized DFS over (current square, remaining cells): each turn slides to
+/// any unoccupied cell still in the region, then removes one more cell from
+/// what's left (the destroy), and the value is `1 + max` over every such
+/// (move, destroy) choice, bottoming out at 0 once no move remains.
+/// Partitioned regions are small by the time this runs, so the
+/// `(square, remaining-bitboard)` state space stays cheap in practice.
+pub fn max_tempo(start_pos: (u8, u8), region_mask: u64) -> u32 {
+    let start_idx = pos_to_index(start_pos.0, start_pos.1);
+
+    // Keep the vacated starting square in `remaining` so it's still a legal
+    // destroy target on the first turn; queen move-generation already
+    // excludes the square the queen is standing on as a move target on its
+    // own, so this doesn't let the first move slide back onto it.
+    let mut memo: HashMap<(u8, u64), u32> = HashMap::new();
+    max_tempo_search(start_idx, region_mask, &mut memo)
+}
+
+fn max_tempo_search(pos_idx: u8, remaining: u64, memo: &mut HashMap<(u8, u64), u32>) -> u32 {
+    if let Some(&cached) = memo.get(&(pos_idx, remaining)) {
+        return cached;
+    }
+
+    let pos = index_to_pos(pos_idx);
+    // Can only step onto a cell still in the region; everything outside it
+    // blocks the slide just like a destroyed or occupied cell would.
+    let move_targets = get_queen_moves(pos.0, pos.1, !remaining) & remaining;
+
+    let mut best = 0u32;
+    let mut targets = move_targets;
+    while targets != 0 {
+        let to_idx = targets.trailing_zeros() as u8;
+        targets &= targets - 1;
+
+        let after_move = remaining & !(1u64 << to_idx);
+
+        let mut destroy_candidates = after_move;
+        while destroy_candidates != 0 {
+            let d_idx = destroy_candidates.trailing_zeros() as u8;
+            destroy_candidates &= destroy_candidates - 1;
+
+            let after_destroy = after_move & !(1u64 << d_idx);
+            let value = 1 + max_tempo_search(to_idx, after_destroy, memo);
+            best = best.max(value);
+        }
+    }
+
+    memo.insert((pos_idx, remaining), best);
+    best
+}
+
+/// Exact verdict for an already-partitioned position: compares the true
+/// longest sequence of turns each side can make alone in their region
+/// (`max_tempo`, not raw region size) and returns a decisive ±∞-style score
+/// from the sign of the difference, since whoever runs out of moves first
+/// loses.
+pub fn solve_partitioned(result: &PartitionResult, player_pos: (u8, u8), ai_pos: (u8, u8)) -> i32 {
+    let player_tempo = max_tempo(player_pos, result.player_region) as i32;
+    let ai_tempo = max_tempo(ai_pos, result.ai_region) as i32;
+
+    match player_tempo.cmp(&ai_tempo) {
+        std::cmp::Ordering::Greater => 100_000 + (player_tempo - ai_tempo),
+        std::cmp::Ordering::Less => -100_000 - (ai_tempo - player_tempo),
+        std::cmp::Ordering::Equal => 0,
+    }
+}
+
+/// Recognize a partitioned position and seed the transposition table with
+/// its exact outcome, so `alpha_beta` treats the node as solved instead of
+/// re-expanding the (often huge) subtree below it every time the position
+/// is reached.
+///
+/// Floods the empty graph from both queens (`detect_partition_bitboard`,
+/// queen-move BFS); if the two flood sets are disjoint the position is
+/// partitioned and forced, so `solve_partitioned` (longest self-avoiding
+/// walk per side, via `max_tempo`) settles it exactly with no further
+/// minimax. Returns the negamax score relative to `maximizing` when the
+/// position was partitioned, caching it in `tt` as a maximal-depth
+/// `Bound::Exact` entry; returns `None` (and touches `tt` not at all)
+/// otherwise, so the caller falls through to ordinary search.
+pub fn solve_and_cache_partition(
+    state: &GameState,
+    maximizing: bool,
+    tt: &mut TranspositionTable,
+    hash: u64,
+) -> Option<i32> {
+    let player_idx = safe_get_position_index(state.player)?;
+    let ai_idx = safe_get_position_index(state.ai)?;
+    let player_pos = index_to_pos(player_idx);
+    let ai_pos = index_to_pos(ai_idx);
+
+    let partition = detect_partition_bitboard(player_pos, ai_pos, state.destroyed);
+    // `is_partitioned` alone is not enough: the player's flood treats the AI
+    // square as blocked and never reaches it, so check that the two flood
+    // sets really are disjoint.
+    if !partition.is_partitioned || (partition.player_region & partition.ai_region) != 0 {
+        return None;
+    }
+
+    // `solve_partitioned` is positive when the player out-tempos the AI;
+    // `alpha_beta`'s scores are negamax, relative to whoever is on move.
+    let player_relative = solve_partitioned(&partition, player_pos, ai_pos);
+    let score = if maximizing { -player_relative } else { player_relative };
+
+    tt.store(hash, u8::MAX, score, Bound::Exact, None);
+    Some(score)
+}
+
 /// Quick estimate of longest path using cell count
 pub fn estimate_longest_path(cell_count: i32) -> i32 {
     // Heuristic: cells * efficiency factor
@@ -302,6 +643,182 @@ pub fn should_solve_exactly(cell_count: i32) -> bool {
     cell_count <= 18
 }
 
+/// Simulated-annealing fallback for regions too large for
+/// `should_solve_exactly`'s exact DFS. Rather than `estimate_longest_path`'s
+/// bare cell-count guess (no move attached), this builds an actual simple
+/// queen-move path - seeded with the Warnsdorff-greedy walk - and locally
+/// improves it for the remaining time budget, returning its first step as a
+/// real, strong move.
+fn solve_endgame_annealed(
+    state: &GameState,
+    reachable_region: u64,
+    is_ai: bool,
+    time_limit_ms: u32,
+) -> EndgameResult {
+    let start_time = js_sys::Date::now();
+    let time_limit = (time_limit_ms as f64).max(1.0);
+
+    let position = if is_ai { state.ai } else { state.player };
+    let pos_idx = position.trailing_zeros() as u8;
+    let start_pos = index_to_pos(pos_idx);
+
+    let blocked = state.destroyed | !reachable_region;
+
+    let mut seed = 0x9E3779B97F4A7C15u64 ^ (pos_idx as u64);
+
+    let mut path = warnsdorff_path(start_pos, blocked, reachable_region);
+    let mut best_path = path.clone();
+
+    // Initial temperature chosen so an early worsening move (a couple of
+    // cells shorter) is still often accepted; decaying geometrically down
+    // to a small fraction of that over the time budget lets the search
+    // settle into hill-climbing by the end.
+    const INITIAL_TEMPERATURE: f64 = 8.0;
+    const FINAL_TEMPERATURE_RATIO: f64 = 0.001;
+
+    loop {
+        let elapsed = js_sys::Date::now() - start_time;
+        if elapsed >= time_limit {
+            break;
+        }
+
+        let progress = elapsed / time_limit;
+        let temperature = INITIAL_TEMPERATURE * FINAL_TEMPERATURE_RATIO.powf(progress);
+
+        let candidate = if next_rand(&mut seed) % 2 == 0 {
+            truncate_and_reextend(&path, blocked, reachable_region, &mut seed)
+        } else {
+            reverse_suffix(&path, blocked, &mut seed)
+        };
+
+        let delta = path.len() as f64 - candidate.len() as f64;
+        let accept = delta <= 0.0 || random_unit(&mut seed) < (-delta / temperature).exp();
+
+        if accept {
+            path = candidate;
+        }
+
+        if path.len() > best_path.len() {
+            best_path = path.clone();
+        }
+    }
+
+    let path_length = (best_path.len() - 1) as i32;
+    let best_move = if best_path.len() > 1 {
+        let to_pos = best_path[1];
+        let destroy_pos = find_best_endgame_destroy(state, to_pos, reachable_region, is_ai);
+        Some(Move {
+            from: start_pos,
+            to: to_pos,
+            destroy: destroy_pos,
+            score: path_length,
+        })
+    } else {
+        None
+    };
+
+    EndgameResult {
+        best_move,
+        longest_path: path_length,
+        solved: false,
+        confidence: EndgameConfidence::Heuristic,
+    }
+}
+
+/// Greedily walk from `start_pos` always stepping to the reachable,
+/// unvisited cell with the fewest onward moves (Warnsdorff's rule), until
+/// no legal move remains. Used to seed the annealed search with a
+/// reasonable path rather than starting it from a single cell.
+fn warnsdorff_path(start_pos: (u8, u8), blocked: u64, valid_cells: u64) -> Vec<(u8, u8)> {
+    let mut path = vec![start_pos];
+    let mut visited = pos_to_mask(start_pos.0, start_pos.1);
+    let mut current = start_pos;
+
+    loop {
+        let mut moves = get_moves_from_position(current, blocked | visited, valid_cells, true);
+        if moves.is_empty() {
+            break;
+        }
+        current = moves.remove(0);
+        visited |= pos_to_mask(current.0, current.1);
+        path.push(current);
+    }
+
+    path
+}
+
+/// Neighbor operator (a): cut the path at a random point and rebuild the
+/// rest of it by repeatedly stepping to a uniformly random legal move
+/// instead of re-running the (deterministic) Warnsdorff seed, so repeated
+/// applications explore genuinely different continuations.
+fn truncate_and_reextend(path: &[(u8, u8)], blocked: u64, valid_cells: u64, seed: &mut u64) -> Vec<(u8, u8)> {
+    let cut = 1 + (next_rand(seed) % path.len() as u64) as usize;
+    let mut new_path = path[..cut].to_vec();
+
+    let mut visited = 0u64;
+    for &p in &new_path {
+        visited |= pos_to_mask(p.0, p.1);
+    }
+    let mut current = *new_path.last().unwrap();
+
+    loop {
+        let moves = get_moves_from_position(current, blocked | visited, valid_cells, false);
+        if moves.is_empty() {
+            break;
+        }
+        current = moves[(next_rand(seed) % moves.len() as u64) as usize];
+        visited |= pos_to_mask(current.0, current.1);
+        new_path.push(current);
+    }
+
+    new_path
+}
+
+/// Neighbor operator (b): reverse a random suffix of the path, which swaps
+/// which end it can be extended from next round. The reversed segment is
+/// re-walked from the reconnection point and truncated at the first step
+/// that's no longer a legal queen move - reversing changes which cells are
+/// "already visited" at the time each move in the suffix is made, so a move
+/// that was legal in the original order isn't guaranteed to stay legal.
+fn reverse_suffix(path: &[(u8, u8)], blocked: u64, seed: &mut u64) -> Vec<(u8, u8)> {
+    if path.len() < 3 {
+        return path.to_vec();
+    }
+
+    let split = 1 + (next_rand(seed) % (path.len() as u64 - 1)) as usize;
+    let mut reordered = path[..split].to_vec();
+    reordered.extend(path[split..].iter().rev());
+
+    let mut visited = pos_to_mask(reordered[0].0, reordered[0].1);
+    let mut validated = vec![reordered[0]];
+
+    for &next in &reordered[1..] {
+        let moves_bb = get_queen_moves(validated.last().unwrap().0, validated.last().unwrap().1, blocked | visited);
+        if (moves_bb & pos_to_mask(next.0, next.1)) == 0 {
+            break;
+        }
+        visited |= pos_to_mask(next.0, next.1);
+        validated.push(next);
+    }
+
+    validated
+}
+
+/// Simple LCG step, matching the fixed-seed construction used for the MCTS
+/// and SPSA-tuner random streams elsewhere in this crate.
+fn next_rand(seed: &mut u64) -> u64 {
+    *seed = seed
+        .wrapping_mul(6364136223846793005u64)
+        .wrapping_add(1442695040888963407u64);
+    *seed
+}
+
+/// Uniform `f64` in `[0, 1)`, built from the top 53 bits of `next_rand` so
+/// it has full `f64` mantissa precision.
+fn random_unit(seed: &mut u64) -> f64 {
+    (next_rand(seed) >> 11) as f64 / (1u64 << 53) as f64
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -318,7 +835,9 @@ mod tests {
         let blocked = 0u64;
         let visited = 1u64 << pos_to_index(0, 0);
 
-        let result = longest_path_from_position(start_pos, reachable, blocked, visited, 1000);
+        let mut memo = PathMemo::new();
+        let result =
+            longest_path_from_position(start_pos, reachable, blocked, visited, 1000, &mut memo, true);
 
         // Should be able to reach 2 more cells
         assert_eq!(result.length, 2);
@@ -338,5 +857,46 @@ mod tests {
         assert_eq!(estimate_longest_path(10), 7);
         assert_eq!(estimate_longest_path(20), 15);
     }
+
+    #[test]
+    fn test_max_tempo_single_cell_region() {
+        // Only the starting cell is in the region: no move is possible.
+        let region = 1u64 << pos_to_index(3, 3);
+        assert_eq!(max_tempo((3, 3), region), 0);
+    }
+
+    #[test]
+    fn test_max_tempo_straight_line() {
+        // A 1x4 strip: the queen can slide, destroy behind it, and repeat
+        // until the strip is exhausted.
+        let mut region = 0u64;
+        for c in 0..4 {
+            region |= 1u64 << pos_to_index(0, c);
+        }
+
+        // 4 cells: 2 full (move, destroy) turns are obtainable before the
+        // strip runs out, regardless of which end is consumed first.
+        assert_eq!(max_tempo((0, 0), region), 2);
+    }
+
+    #[test]
+    fn test_solve_partitioned_prefers_larger_tempo() {
+        let mut player_region = 0u64;
+        for c in 0..4 {
+            player_region |= 1u64 << pos_to_index(0, c);
+        }
+        let ai_region = 1u64 << pos_to_index(6, 6);
+
+        let result = PartitionResult {
+            is_partitioned: true,
+            player_region_size: count_ones(player_region) as i32,
+            ai_region_size: 1,
+            player_region,
+            ai_region,
+        };
+
+        let score = solve_partitioned(&result, (0, 0), (6, 6));
+        assert!(score > 0, "player with more tempo should win: {}", score);
+    }
 }
 
@@ -17,17 +17,116 @@ use crate::board::GameState;
 use crate::bitboard::*;
 use crate::voronoi::*;
 use crate::partition::*;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
 
-/// Cache for critical cells computation
-/// Uses position hash as key to avoid recomputation
+/// Zobrist keys for hashing ISOLATION positions, used below to key the
+/// whole-evaluation cache. Unlike the Zobrist scheme in `transposition.rs`
+/// (which also folds in whose turn it is, for search hashing), this one only
+/// needs to identify a position: `evaluate_advanced` always scores the same
+/// fixed `player`/`ai` roles, not "side to move".
+struct EvalZobrist {
+    player: [u64; 49],
+    ai: [u64; 49],
+    destroyed: [u64; 49],
+}
+
+impl EvalZobrist {
+    fn new() -> Self {
+        // Same LCG construction as `TranspositionTable::new`, seeded
+        // differently so the two key spaces don't collide if ever compared.
+        let mut seed = 0xD1B54A32D192ED03u64;
+        let mut next_random = || {
+            seed = seed.wrapping_mul(6364136223846793005u64).wrapping_add(1442695040888963407u64);
+            seed
+        };
+
+        let mut player = [0u64; 49];
+        let mut ai = [0u64; 49];
+        let mut destroyed = [0u64; 49];
+        for i in 0..49 {
+            player[i] = next_random();
+            ai[i] = next_random();
+            destroyed[i] = next_random();
+        }
+
+        EvalZobrist { player, ai, destroyed }
+    }
+
+    fn hash(&self, state: &GameState) -> u64 {
+        let mut hash = 0u64;
+        if let Some(idx) = safe_get_position_index(state.player) {
+            hash ^= self.player[idx as usize];
+        }
+        if let Some(idx) = safe_get_position_index(state.ai) {
+            hash ^= self.ai[idx as usize];
+        }
+        let mut destroyed = state.destroyed;
+        while destroyed != 0 {
+            let idx = destroyed.trailing_zeros();
+            hash ^= self.destroyed[idx as usize];
+            destroyed &= destroyed - 1;
+        }
+        hash
+    }
+}
+
+/// A memoized `evaluate_advanced` result, keyed by `EvalZobrist` hash, plus
+/// the critical-cell vector `find_critical_cells_uncached` computed along the
+/// way (previously its own one-off cache, now folded into this one).
+#[derive(Clone)]
+struct EvalCacheEntry {
+    hash: u64,
+    score: i32,
+    components: EvalComponents,
+    critical_cells: Vec<u8>,
+    generation: u32,
+}
+
 thread_local! {
-    static CRITICAL_CELLS_CACHE: RefCell<HashMap<u64, Vec<u8>>> = RefCell::new(HashMap::new());
+    static EVAL_ZOBRIST: EvalZobrist = EvalZobrist::new();
+    static EVAL_CACHE: RefCell<HashMap<u64, EvalCacheEntry>> = RefCell::new(HashMap::new());
+    static EVAL_GENERATION: Cell<u32> = Cell::new(0);
 }
 
 const CACHE_MAX_SIZE: usize = 1000;
 
+fn eval_cache_lookup(hash: u64) -> Option<(i32, EvalComponents, Vec<u8>)> {
+    EVAL_CACHE.with(|cache| {
+        cache.borrow().get(&hash).and_then(|entry| {
+            // Hash match is already guaranteed by the HashMap key, but a
+            // 64-bit Zobrist key is collision-resistant in practice, not
+            // collision-proof, so verify before trusting a stale result.
+            if entry.hash == hash {
+                Some((entry.score, entry.components, entry.critical_cells.clone()))
+            } else {
+                None
+            }
+        })
+    })
+}
+
+/// Replacement strategy: once full, drop every entry more than two
+/// generations older than this store's. The generation advances on every
+/// store, so a full cache keeps only its two most recent entries.
+fn eval_cache_store(hash: u64, score: i32, components: EvalComponents, critical_cells: Vec<u8>) {
+    EVAL_CACHE.with(|cache| {
+        let generation = EVAL_GENERATION.with(|g| {
+            let next = g.get().wrapping_add(1);
+            g.set(next);
+            next
+        });
+
+        let mut cache = cache.borrow_mut();
+        if cache.len() >= CACHE_MAX_SIZE {
+            let min_generation = generation.saturating_sub(2);
+            cache.retain(|_, entry| entry.generation >= min_generation);
+        }
+
+        cache.insert(hash, EvalCacheEntry { hash, score, components, critical_cells, generation });
+    });
+}
+
 /// Evaluation weights for different difficulty levels
 #[derive(Clone, Copy, Debug)]
 pub struct EvalWeights {
@@ -42,6 +141,7 @@ pub struct EvalWeights {
     pub parity: f32,
     pub trap: f32,
     pub effective_mobility: f32,
+    pub rollout: f32,
 }
 
 impl EvalWeights {
@@ -59,6 +159,7 @@ impl EvalWeights {
             parity: 20.0,
             trap: 100.0,
             effective_mobility: 3.0,
+            rollout: 15.0,
         }
     }
 
@@ -76,6 +177,7 @@ impl EvalWeights {
             parity: 5.0,
             trap: 20.0,
             effective_mobility: 1.0,
+            rollout: 8.0,
         }
     }
 
@@ -93,6 +195,7 @@ impl EvalWeights {
             parity: 0.0,
             trap: 0.0,
             effective_mobility: 0.0,
+            rollout: 0.0,
         }
     }
 }
@@ -142,6 +245,7 @@ pub struct EvalComponents {
     pub parity: f32,
     pub trap: f32,
     pub effective_mobility: f32,
+    pub rollout: f32,
 }
 
 /// Precomputed center distance table (Manhattan distance from center (3,3))
@@ -168,6 +272,11 @@ const CORNER_PROXIMITY: [i32; 49] = [
 
 /// Advanced evaluation function for NEXUS-5 and NEXUS-7
 pub fn evaluate_advanced(state: &GameState, weights: &EvalWeights) -> (i32, EvalComponents) {
+    let cache_hash = EVAL_ZOBRIST.with(|z| z.hash(state));
+    if let Some((score, components, _critical_cells)) = eval_cache_lookup(cache_hash) {
+        return (score, components);
+    }
+
     let destroyed_count = count_ones(state.destroyed);
     let blocked = state.destroyed | state.player | state.ai;
 
@@ -176,7 +285,7 @@ pub fn evaluate_advanced(state: &GameState, weights: &EvalWeights) -> (i32, Eval
         None => return (0, EvalComponents {
             territory: 0.0, mobility: 0.0, mobility_potential: 0.0,
             center_control: 0.0, corner_avoidance: 0.0, partition_advantage: 0.0,
-            critical_cells: 0.0, openness: 0.0, parity: 0.0, trap: 0.0, effective_mobility: 0.0,
+            critical_cells: 0.0, openness: 0.0, parity: 0.0, trap: 0.0, effective_mobility: 0.0, rollout: 0.0,
         }),
     };
     let ai_idx = match safe_get_position_index(state.ai) {
@@ -184,7 +293,7 @@ pub fn evaluate_advanced(state: &GameState, weights: &EvalWeights) -> (i32, Eval
         None => return (0, EvalComponents {
             territory: 0.0, mobility: 0.0, mobility_potential: 0.0,
             center_control: 0.0, corner_avoidance: 0.0, partition_advantage: 0.0,
-            critical_cells: 0.0, openness: 0.0, parity: 0.0, trap: 0.0, effective_mobility: 0.0,
+            critical_cells: 0.0, openness: 0.0, parity: 0.0, trap: 0.0, effective_mobility: 0.0, rollout: 0.0,
         }),
     };
     let player_pos = index_to_pos(player_idx);
@@ -192,14 +301,42 @@ pub fn evaluate_advanced(state: &GameState, weights: &EvalWeights) -> (i32, Eval
 
     // 1. Partition analysis (Do this FIRST as it might skip other components)
     let partition = detect_partition_bitboard(player_pos, ai_pos, state.destroyed);
-    
+
+    // Once both pieces are isolated in their own region, the outcome is fully
+    // determined by who can out-tempo the other there. Solve it exactly instead
+    // of falling through to the region-size/parity proxies below.
+    if partition.is_partitioned {
+        let score = solve_partitioned_endgame(state, &partition);
+        let components = EvalComponents {
+            territory: partition.ai_region_size as f32 - partition.player_region_size as f32,
+            mobility: 0.0, mobility_potential: 0.0,
+            center_control: 0.0, corner_avoidance: 0.0,
+            partition_advantage: score as f32,
+            critical_cells: 0.0, openness: 0.0, parity: 0.0, trap: 0.0, effective_mobility: 0.0, rollout: 0.0,
+        };
+        eval_cache_store(cache_hash, score, components, Vec::new());
+        return (score, components);
+    }
+
     // 2. Territory analysis using Voronoi
     // If partitioned, territory is simply the region sizes
     let (voronoi_ai, voronoi_player, voronoi_contested) = if partition.is_partitioned {
         (partition.ai_region_size as f32, partition.player_region_size as f32, 0.0)
     } else {
-        let voronoi = calculate_voronoi_optimized(player_pos, ai_pos, state.destroyed);
-        (voronoi.ai_count as f32, voronoi.player_count as f32, voronoi.contested_count as f32)
+        let voronoi = calculate_voronoi_optimized(player_pos, ai_pos, state.destroyed, ContestedTiePolicy::KeepContested);
+
+        // Cells that are only claimed because a single destroyed cell walls
+        // the two territories apart are one captured wall away from flipping
+        // sides - discount them instead of counting them at full weight.
+        let fragile = detect_breakins(&voronoi, state.destroyed);
+        let fragile_ai = count_ones(fragile & voronoi.ai_territory) as f32;
+        let fragile_player = count_ones(fragile & voronoi.player_territory) as f32;
+
+        (
+            voronoi.ai_count as f32 - fragile_ai * 0.5,
+            voronoi.player_count as f32 - fragile_player * 0.5,
+            voronoi.contested_count as f32,
+        )
     };
     
     let territory_score = (voronoi_ai - voronoi_player) + (voronoi_contested * 0.4);
@@ -235,18 +372,35 @@ pub fn evaluate_advanced(state: &GameState, weights: &EvalWeights) -> (i32, Eval
     let player_corner_dist = CORNER_PROXIMITY[player_idx as usize];
     let corner_score = (ai_corner_dist - player_corner_dist) as f32;
 
-    // 6. Partition score
+    // 6. Partition score: chamber decomposition over the free-cell graph
+    // (`analyze_chambers`) instead of the old one-shot `find_critical_cells`/
+    // `evaluate_partition_threat` scan, which only ever reasoned about a
+    // single near-partition. This attributes every chamber in flight to
+    // whichever side's BFS reaches it first and weighs in the single cut
+    // cell most worth fighting over, so several simultaneous near-splits
+    // are accounted for instead of just the first one found.
+    let mut critical_cells_found: Vec<u8> = Vec::new();
     let partition_score = if partition.is_partitioned {
         // Already partitioned - huge advantage/disadvantage based on region sizes
         ((partition.ai_region_size - partition.player_region_size) * 5) as f32
     } else if destroyed_count > 12 {
         // Only check for near-partition situations if board is somewhat filled
-        let critical_cells = find_critical_cells(state, blocked);
-        if !critical_cells.is_empty() && critical_cells.len() <= 3 {
-            evaluate_partition_threat(state, blocked, &critical_cells)
-        } else {
-            0.0
-        }
+        critical_cells_found = find_critical_cells_uncached(state, blocked);
+
+        let graph = analyze_chambers(state, blocked);
+        let ai_territory: i32 = graph.chambers.iter()
+            .filter(|c| c.owner == Some(true))
+            .map(|c| c.size as i32)
+            .sum();
+        let player_territory: i32 = graph.chambers.iter()
+            .filter(|c| c.owner == Some(false))
+            .map(|c| c.size as i32)
+            .sum();
+        let cut_cell_risk = graph.best_cut_cell
+            .and_then(|idx| graph.cut_cells.iter().find(|c| c.idx == idx).map(|c| c.value))
+            .unwrap_or(0);
+
+        ((ai_territory - player_territory) * 3 + cut_cell_risk) as f32
     } else {
         0.0
     };
@@ -256,7 +410,13 @@ pub fn evaluate_advanced(state: &GameState, weights: &EvalWeights) -> (i32, Eval
         // Simplified check: use partition logic if available
         0.0 // Currently too expensive for leaf nodes
     } else {
-        0.0
+        let cells = find_critical_cells_uncached(state, blocked);
+        if cells.is_empty() {
+            0.0
+        } else {
+            let (_, paths) = calculate_voronoi_with_paths(player_pos, ai_pos, state.destroyed);
+            evaluate_critical_cell_tempo(&paths, &cells)
+        }
     };
 
     // 8. Openness
@@ -295,6 +455,16 @@ pub fn evaluate_advanced(state: &GameState, weights: &EvalWeights) -> (i32, Eval
         0.0
     };
 
+    // 12. Monte-Carlo rollout (Only near the horizon, where a few cheap
+    // playouts are worth more than another static term: desperate AI or
+    // deep midgame, matching the mobility_potential gating above).
+    let rollout_score = if weights.rollout > 0.0
+        && (ai_mobility_count <= 4 || (destroyed_count > 15 && destroyed_count < 35))
+    {
+        rollout_eval(state, 12, 6)
+    } else {
+        0.0
+    };
 
     // Combine all components
     let components = EvalComponents {
@@ -309,6 +479,7 @@ pub fn evaluate_advanced(state: &GameState, weights: &EvalWeights) -> (i32, Eval
         parity: parity_score,
         trap: trap_score,
         effective_mobility: effective_mobility_score,
+        rollout: rollout_score,
     };
 
     let score = (
@@ -322,12 +493,230 @@ pub fn evaluate_advanced(state: &GameState, weights: &EvalWeights) -> (i32, Eval
         openness_score * weights.openness +
         parity_score * weights.parity +
         trap_score * weights.trap +
-        effective_mobility_score * weights.effective_mobility
+        effective_mobility_score * weights.effective_mobility +
+        rollout_score * weights.rollout
     ) as i32;
 
+    eval_cache_store(cache_hash, score, components, critical_cells_found);
+
     (score, components)
 }
 
+/// Exact score for a fully partitioned position.
+///
+/// Once a player is isolated in their own region, the game is a solitaire
+/// longest-walk problem: each piece traces a simple path through the free
+/// cells of its region, and whoever runs out of moves first (given whose
+/// turn it is) loses. Solve both sides exactly via `longest_simple_path` and
+/// decide the winner from the two lengths and turn parity.
+pub fn solve_partitioned_endgame(state: &GameState, partition: &PartitionResult) -> i32 {
+    let ai_idx = match safe_get_position_index(state.ai) {
+        Some(idx) => idx,
+        None => return -1_000_000,
+    };
+    let player_idx = match safe_get_position_index(state.player) {
+        Some(idx) => idx,
+        None => return 1_000_000,
+    };
+
+    // Free cells within each region, excluding the piece that starts there
+    // (the piece's own cell isn't a cell it can still move onto).
+    let ai_free = partition.ai_region & !(1u64 << ai_idx);
+    let player_free = partition.player_region & !(1u64 << player_idx);
+
+    let ai_len = longest_simple_path(ai_idx, ai_free);
+    let player_len = longest_simple_path(player_idx, player_free);
+
+    // It's the AI's turn in this leaf position. The mover loses the race
+    // when their path is not strictly longer than the opponent's.
+    let ai_wins = ai_len > player_len;
+
+    if ai_wins {
+        1_000_000 - (player_len as i32)
+    } else {
+        -1_000_000 + (ai_len as i32)
+    }
+}
+
+/// Longest simple path starting at `start_idx`, stepping via queen-move
+/// adjacency through the cells set in `free_mask`.
+///
+/// Isolated endgame regions are small, so a depth-first search with
+/// branch-and-bound (the remaining free-cell count upper-bounds how much
+/// longer the path could still get) is exact and fast.
+fn longest_simple_path(start_idx: u8, free_mask: u64) -> u32 {
+    let mut best = 0u32;
+    longest_simple_path_dfs(start_idx, free_mask, 0, &mut best);
+    best
+}
+
+fn longest_simple_path_dfs(pos_idx: u8, remaining: u64, depth: u32, best: &mut u32) {
+    if depth > *best {
+        *best = depth;
+    }
+
+    // Upper bound: even visiting every remaining free cell can't beat `best`.
+    if depth + count_ones(remaining) <= *best {
+        return;
+    }
+
+    let (r, c) = index_to_pos(pos_idx);
+    let mut moves = get_queen_moves(r, c, !remaining) & remaining;
+
+    while moves != 0 {
+        let next_idx = moves.trailing_zeros() as u8;
+        let next_mask = 1u64 << next_idx;
+        longest_simple_path_dfs(next_idx, remaining & !next_mask, depth + 1, best);
+        moves &= moves - 1;
+    }
+}
+
+/// Minimal deterministic PRNG for reproducible rollouts (no extra crate needed).
+struct XorShift(u64);
+
+impl XorShift {
+    fn new(seed: u64) -> Self {
+        XorShift(if seed == 0 { 0x9E3779B97F4A7C15 } else { seed })
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+        x
+    }
+
+    fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+}
+
+/// Cheap subset of the evaluation used to score moves inside a rollout
+/// playout: mobility + center control + trap avoidance only.
+fn cheap_playout_score(state: &GameState) -> i32 {
+    let blocked = state.destroyed | state.player | state.ai;
+    let ai_idx = match safe_get_position_index(state.ai) {
+        Some(idx) => idx,
+        None => return -10_000,
+    };
+    let player_idx = match safe_get_position_index(state.player) {
+        Some(idx) => idx,
+        None => return 10_000,
+    };
+    let (ar, ac) = index_to_pos(ai_idx);
+    let (pr, pc) = index_to_pos(player_idx);
+
+    let ai_mobility = count_ones(get_queen_moves(ar, ac, blocked)) as i32;
+    let player_mobility = count_ones(get_queen_moves(pr, pc, blocked)) as i32;
+
+    let mut score = (ai_mobility - player_mobility) * 8;
+    score += CENTER_DISTANCE[player_idx as usize] - CENTER_DISTANCE[ai_idx as usize];
+
+    if ai_mobility == 0 {
+        score -= 10_000;
+    }
+    if player_mobility == 0 {
+        score += 10_000;
+    }
+
+    score
+}
+
+/// Play `state` forward from the side to move for up to `depth` plies using
+/// a lightweight greedy/softmax policy, returning the game's outcome from
+/// the AI's perspective: +1 win, -1 loss; if the ply budget runs out first,
+/// the sign of the cheap static eval at that point (0 when it is level).
+fn playout(state: &GameState, depth: u32, ai_to_move: bool, rng: &mut XorShift) -> f32 {
+    let mut cur = *state;
+    let mut ai_turn = ai_to_move;
+
+    for _ in 0..depth {
+        let moves = cur.get_valid_moves(ai_turn);
+        if moves.is_empty() {
+            return if ai_turn { -1.0 } else { 1.0 };
+        }
+
+        // Score each candidate `to` square with the cheap subset of the
+        // evaluation, picking a destroy target heuristically via the
+        // existing search-layer candidate generator.
+        let mut best_mv = None;
+        let mut best_score = i32::MIN;
+        let mut weighted: Vec<(crate::board::Move, f32)> = Vec::with_capacity(moves.len());
+
+        for mut mv in moves {
+            let destroy_candidates = crate::search::get_destroy_candidates_advanced_export(&cur, &mv, ai_turn, 1);
+            mv.destroy = destroy_candidates.into_iter().next().unwrap_or((0, 0));
+
+            let mut next = cur;
+            if ai_turn {
+                next.ai = crate::bitboard::pos_to_mask(mv.to.0, mv.to.1);
+            } else {
+                next.player = crate::bitboard::pos_to_mask(mv.to.0, mv.to.1);
+            }
+            next.destroyed |= crate::bitboard::pos_to_mask(mv.destroy.0, mv.destroy.1);
+
+            let s = cheap_playout_score(&next);
+            let s = if ai_turn { s } else { -s };
+
+            if s > best_score {
+                best_score = s;
+                best_mv = Some(next);
+            }
+            weighted.push((mv, s as f32));
+        }
+
+        // Sample proportionally to exp(score/tau) for playout diversity,
+        // falling back to the best move if sampling degenerates.
+        const TAU: f32 = 40.0;
+        let max_s = weighted.iter().map(|(_, s)| *s).fold(f32::MIN, f32::max);
+        let total: f32 = weighted.iter().map(|(_, s)| ((s - max_s) / TAU).exp()).sum();
+        let mut pick = rng.next_f32() * total;
+        let mut chosen = best_mv;
+        for (mv, s) in &weighted {
+            let w = ((*s - max_s) / TAU).exp();
+            if pick < w {
+                let mut next = cur;
+                if ai_turn {
+                    next.ai = crate::bitboard::pos_to_mask(mv.to.0, mv.to.1);
+                } else {
+                    next.player = crate::bitboard::pos_to_mask(mv.to.0, mv.to.1);
+                }
+                next.destroyed |= crate::bitboard::pos_to_mask(mv.destroy.0, mv.destroy.1);
+                chosen = Some(next);
+                break;
+            }
+            pick -= w;
+        }
+
+        cur = chosen.unwrap_or(cur);
+        ai_turn = !ai_turn;
+    }
+
+    // Ply budget exhausted: fall back to the sign of the cheap static eval.
+    let s = cheap_playout_score(&cur);
+    if s > 0 { 1.0 } else if s < 0 { -1.0 } else { 0.0 }
+}
+
+/// Monte-Carlo rollout estimator, blended into `evaluate_advanced` as an
+/// extra component alongside the static terms.
+///
+/// Runs `playouts` independent simulations of up to `depth` plies from
+/// `state`, averages the outcome into a win-rate in [-1, 1] from the AI's
+/// perspective, and scales it into the same rough units as the other
+/// evaluation components.
+pub fn rollout_eval(state: &GameState, playouts: u32, depth: u32) -> f32 {
+    let mut rng = XorShift::new(state.player ^ state.ai.rotate_left(17) ^ state.destroyed.rotate_left(31));
+
+    let mut total = 0.0f32;
+    for _ in 0..playouts {
+        total += playout(state, depth, true, &mut rng);
+    }
+
+    (total / playouts as f32) * 10.0
+}
+
 /// Calculate parity advantage
 fn evaluate_parity(_state: &GameState, voronoi: &VoronoiResult) -> f32 {
     // Parity concept: In a partitioned game, the player with the larger odd/even compatible region wins.
@@ -486,58 +875,12 @@ fn calculate_mobility_potential(state: &GameState, blocked: u64) -> f32 {
     ai_potential - player_potential
 }
 
-/// Compute cache key for critical cells
-/// Uses position hash based on player, AI, and destroyed cells
-fn compute_critical_cells_cache_key(state: &GameState) -> u64 {
-    let player_idx = safe_get_position_index(state.player).unwrap_or(64); // Use 64 as safe default for hash
-    let ai_idx = safe_get_position_index(state.ai).unwrap_or(64);
-
-    // Simple hash: combine player, AI, and destroyed positions
-    let mut hash = (player_idx as u64) | ((ai_idx as u64) << 8);
-
-    // XOR in destroyed cells (simplified)
-    let mut destroyed = state.destroyed;
-    while destroyed != 0 {
-        let idx = destroyed.trailing_zeros();
-        hash ^= (idx as u64) << (idx % 32);
-        destroyed &= destroyed - 1;
-    }
-
-    hash
-}
-
-/// Find cells that would cause partition if destroyed (with caching)
-fn find_critical_cells(state: &GameState, blocked: u64) -> Vec<u8> {
-    let cache_key = compute_critical_cells_cache_key(state);
-
-    // Try cache first
-    let cached = CRITICAL_CELLS_CACHE.with(|cache| {
-        cache.borrow().get(&cache_key).cloned()
-    });
-
-    if let Some(result) = cached {
-        return result;
-    }
-
-    // Cache miss - compute
-    let result = find_critical_cells_uncached(state, blocked);
-
-    // Store in cache
-    CRITICAL_CELLS_CACHE.with(|cache| {
-        let mut cache = cache.borrow_mut();
-
-        // Evict if cache is full (simple LRU: clear all)
-        if cache.len() >= CACHE_MAX_SIZE {
-            cache.clear();
-        }
-
-        cache.insert(cache_key, result.clone());
-    });
-
-    result
-}
-
-/// Find cells that would cause partition if destroyed (uncached implementation)
+/// Find cells that would cause partition if destroyed.
+///
+/// Previously had its own one-off position-hash cache; that hash wasn't
+/// collision-resistant (distinct positions could alias), and the whole
+/// result is now memoized anyway as part of `evaluate_advanced`'s Zobrist
+/// cache, so this just computes directly.
 fn find_critical_cells_uncached(state: &GameState, blocked: u64) -> Vec<u8> {
     let player_idx = match safe_get_position_index(state.player) {
         Some(idx) => idx,
@@ -551,6 +894,7 @@ fn find_critical_cells_uncached(state: &GameState, blocked: u64) -> Vec<u8> {
     let ai_pos = index_to_pos(ai_idx);
 
     let mut critical: Vec<u8> = Vec::new();
+    let mut checked: u64 = 0;
 
     // Only check cells in the "path" between players
     let min_r = player_pos.0.min(ai_pos.0);
@@ -564,14 +908,25 @@ fn find_critical_cells_uncached(state: &GameState, blocked: u64) -> Vec<u8> {
     let search_min_c = min_c.saturating_sub(1);
     let search_max_c = (max_c + 1).min(6);
 
+    // A cell can only matter if a queen slide from one of the two pieces
+    // could reach it at all; skip the (usually unnecessary) partition check
+    // for anything outside that reach.
+    let reachable = queen_pseudo_attacks(player_idx) | queen_pseudo_attacks(ai_idx);
+
     for r in search_min_r..=search_max_r {
         for c in search_min_c..=search_max_c {
             let idx = pos_to_index(r, c);
+            let bit = 1u64 << idx;
 
             // Skip blocked cells
-            if (blocked & (1u64 << idx)) != 0 {
+            if (blocked & bit) != 0 {
                 continue;
             }
+            if (reachable & bit) == 0 {
+                continue;
+            }
+
+            checked |= bit;
 
             // Check if destroying this would partition
             if would_cause_partition(player_pos, ai_pos, state.destroyed, (r, c)) {
@@ -580,49 +935,29 @@ fn find_critical_cells_uncached(state: &GameState, blocked: u64) -> Vec<u8> {
         }
     }
 
+    // The bounding box above can miss cut cells that lie on the queen line
+    // through the two pieces but beyond either piece, outside its +1 margin -
+    // e.g. a long diagonal on an otherwise empty board. Check those too.
+    let mut line = aligned_line(player_idx, ai_idx) & !blocked & !checked;
+    while line != 0 {
+        let idx = line.trailing_zeros() as u8;
+        line &= line - 1;
+
+        let pos = index_to_pos(idx);
+        if would_cause_partition(player_pos, ai_pos, state.destroyed, pos) {
+            critical.push(idx);
+        }
+    }
+
     critical
 }
 
-/// Clear the critical cells cache (call between games)
+/// Clear the evaluation cache (call between games)
 pub fn clear_critical_cells_cache() {
-    CRITICAL_CELLS_CACHE.with(|cache| {
+    EVAL_CACHE.with(|cache| {
         cache.borrow_mut().clear();
     });
-}
-
-/// Evaluate the threat of partition
-fn evaluate_partition_threat(state: &GameState, blocked: u64, critical_cells: &[u8]) -> f32 {
-    if critical_cells.is_empty() {
-        return 0.0;
-    }
-
-    let player_idx = match safe_get_position_index(state.player) {
-        Some(idx) => idx,
-        None => return 0.0,
-    };
-    let ai_idx = match safe_get_position_index(state.ai) {
-        Some(idx) => idx,
-        None => return 0.0,
-    };
-    let player_pos = index_to_pos(player_idx);
-    let ai_pos = index_to_pos(ai_idx);
-
-    let mut best_advantage: f32 = -1000.0;
-
-    // Check which side would benefit from partition
-    for &idx in critical_cells {
-        let pos = index_to_pos(idx);
-        let result = detect_partition_bitboard(player_pos, ai_pos, state.destroyed | (1u64 << idx));
-
-        if result.is_partitioned {
-            let advantage = (result.ai_region_size - result.player_region_size) as f32;
-            best_advantage = best_advantage.max(advantage);
-        }
-    }
-
-    // If AI can create advantageous partition, that's good
-    // If player can, that's bad for AI
-    best_advantage * 0.5
+    EVAL_GENERATION.with(|g| g.set(0));
 }
 
 /// Evaluate control of critical cells
@@ -631,7 +966,7 @@ fn evaluate_critical_cell_control(
     blocked: u64,
     voronoi: &VoronoiResult,
 ) -> f32 {
-    let critical = find_critical_cells(state, blocked);
+    let critical = find_critical_cells_uncached(state, blocked);
 
     if critical.is_empty() {
         return 0.0;
@@ -651,6 +986,32 @@ fn evaluate_critical_cell_control(
     ((ai_control - player_control) * 2) as f32
 }
 
+/// Evaluate tempo over a set of critical cells: plain territory membership
+/// says who already "owns" a cell, but says nothing about a cell neither
+/// side has claimed yet (contested ground). This instead compares each
+/// side's shortest queen-move distance to each critical cell via
+/// `calculate_voronoi_with_paths`'s backpointers - whoever gets there in
+/// fewer moves has first say over whether it stands or falls.
+fn evaluate_critical_cell_tempo(paths: &VoronoiPaths, critical_cells: &[u8]) -> f32 {
+    if critical_cells.is_empty() {
+        return 0.0;
+    }
+
+    // Neither side can be more than a few dozen queen moves from any cell
+    // on a 7x7 board; treat an unreached cell as maximally distant rather
+    // than unbounded so it still contributes a (large) meaningful gap.
+    const UNREACHED: i32 = CELL_COUNT as i32;
+
+    let mut total = 0.0f32;
+    for &idx in critical_cells {
+        let ai_depth = paths.reconstruct_path(true, idx).map_or(UNREACHED, |p| p.len() as i32 - 1);
+        let player_depth = paths.reconstruct_path(false, idx).map_or(UNREACHED, |p| p.len() as i32 - 1);
+        total += (player_depth - ai_depth) as f32;
+    }
+
+    total / critical_cells.len() as f32
+}
+
 /// Evaluate openness (preference for open areas)
 fn evaluate_openness(state: &GameState, blocked: u64) -> f32 {
     let player_idx = match safe_get_position_index(state.player) {
@@ -715,6 +1076,52 @@ fn evaluate_openness(state: &GameState, blocked: u64) -> f32 {
     ((ai_openness - player_openness) as f32) * 0.3
 }
 
+/// Cheap move-ordering score, estimating a move's merit without running a
+/// full `evaluate_advanced` on the resulting position.
+///
+/// Combines the mobility swing at the destination, the center/corner table
+/// deltas (the same `CENTER_DISTANCE`/`CORNER_PROXIMITY` tables the full
+/// evaluation uses), and large bonuses/penalties when the move traps the
+/// opponent or walks into a trap (via `is_trap_position`). Meant as a sort
+/// key for ordering children before the expensive search recurses into them.
+pub fn score_move_for_ordering(state: &GameState, from: (u8, u8), to: (u8, u8)) -> i32 {
+    let moving_is_ai = state.ai == pos_to_mask(from.0, from.1);
+    let blocked = state.destroyed | state.player | state.ai;
+
+    let from_idx = pos_to_index(from.0, from.1);
+    let to_idx = pos_to_index(to.0, to.1);
+
+    // Mobility delta: the old square stops counting as ours, the new one does.
+    let from_mobility = count_ones(get_queen_moves(from.0, from.1, blocked));
+    let future_blocked = (blocked ^ pos_to_mask(from.0, from.1)) | pos_to_mask(to.0, to.1);
+    let to_mobility = count_ones(get_queen_moves(to.0, to.1, future_blocked));
+    let mobility_delta = to_mobility as i32 - from_mobility as i32;
+
+    // Center/corner table deltas: moving toward the center / away from corners is good.
+    let center_delta = CENTER_DISTANCE[from_idx as usize] - CENTER_DISTANCE[to_idx as usize];
+    let corner_delta = CORNER_PROXIMITY[to_idx as usize] - CORNER_PROXIMITY[from_idx as usize];
+
+    let mut score = mobility_delta * 10 + center_delta * 2 + corner_delta * 2;
+
+    let mut next = *state;
+    if moving_is_ai {
+        next.ai = pos_to_mask(to.0, to.1);
+    } else {
+        next.player = pos_to_mask(to.0, to.1);
+    }
+
+    // Trapping the opponent after this move is worth far more than the
+    // positional terms above; walking into our own trap is worth avoiding.
+    if is_trap_position(&next, moving_is_ai) {
+        score += 5_000; // opponent (the other side) is trapped
+    }
+    if is_trap_position(&next, !moving_is_ai) {
+        score -= 5_000; // we just trapped ourselves
+    }
+
+    score
+}
+
 /// Legacy simple evaluation (for backward compatibility / fallback)
 pub fn evaluate(state: &GameState) -> i32 {
     let destroyed_cnt = count_ones(state.destroyed);
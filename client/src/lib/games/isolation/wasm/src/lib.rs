@@ -8,6 +8,8 @@ mod opening;
 mod voronoi;
 mod partition;
 mod transposition;
+mod tuning;
+mod mcts;
 
 use wasm_bindgen::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -51,6 +53,14 @@ impl IsolationEngine {
         let config = SearchConfig {
             max_depth: depth,
             time_limit_ms,
+            // Soft budget: the engine normally stops well before the hard
+            // cap once the root move has settled down; this is the baseline
+            // before the instability/falling-eval multipliers scale it.
+            soft_time_limit_ms: time_limit_ms / 2,
+            weights: None,
+            // WASM can't spawn OS threads, so this is always 1 here; native
+            // callers of `find_best_move` can opt into lazy-SMP instead.
+            threads: 1,
         };
 
         let best_move = search::find_best_move(&self.state, config);
@@ -65,6 +75,16 @@ impl IsolationEngine {
         serde_wasm_bindgen::to_value(&best_move).unwrap()
     }
 
+    /// Monte-Carlo Tree Search backend: an alternative to the deterministic
+    /// alpha-beta engines above. Degrades gracefully under very short time
+    /// limits and tends to behave differently on blocked, partition-heavy
+    /// endgames since it samples full games instead of a fixed-depth tree.
+    pub fn get_best_move_mcts(&self, time_limit_ms: u32, exploration: f64) -> JsValue {
+        let config = mcts::MctsConfig { time_limit_ms, exploration };
+        let best_move = mcts::find_best_move_mcts(&self.state, config);
+        serde_wasm_bindgen::to_value(&best_move).unwrap()
+    }
+
     /// Evaluate current position with advanced evaluation
     pub fn evaluate_position(&self, difficulty: &str) -> i32 {
         let weights = match difficulty {
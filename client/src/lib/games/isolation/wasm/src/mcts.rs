@@ -0,0 +1,227 @@
+//! Monte-Carlo Tree Search backend for ISOLATION
+//!
+//! An alternative to the deterministic alpha-beta engines in `search` /
+//! `search_advanced`. Runs the standard four MCTS phases - selection via
+//! UCT, expansion of one untried `(to, destroy)` action, a lightly-heuristic
+//! playout to a terminal state, and backpropagation - and returns the root
+//! child with the most visits. Nodes live in a flat `Vec` arena addressed by
+//! index so expansion doesn't churn the allocator node-by-node.
+
+use crate::bitboard::{index_to_pos, pos_to_mask};
+use crate::board::{GameState, Move};
+use crate::search::get_destroy_candidates_advanced_export;
+
+pub struct MctsConfig {
+    pub time_limit_ms: u32,
+    pub exploration: f64,
+}
+
+/// One combined (to, destroy) action - the unit of MCTS expansion.
+#[derive(Clone, Copy, Debug)]
+struct Action {
+    to: (u8, u8),
+    destroy: (u8, u8),
+}
+
+struct Node {
+    state: GameState,
+    /// True if it's the AI's turn to move at this state.
+    maximizing: bool,
+    parent: usize,
+    /// The action applied to `parent`'s state that produced this node; `None` for the root.
+    action: Option<Action>,
+    children: Vec<usize>,
+    /// Actions not yet expanded into a child. Empty + no children means terminal.
+    untried: Vec<Action>,
+    visits: u32,
+    /// Accumulated result for the side that moved *into* this node (i.e. `!maximizing`'s side).
+    wins: f64,
+}
+
+impl Node {
+    fn new(state: GameState, maximizing: bool, parent: usize, action: Option<Action>) -> Self {
+        Node {
+            state,
+            maximizing,
+            parent,
+            action,
+            children: Vec::new(),
+            untried: generate_actions(&state, maximizing),
+            visits: 0,
+            wins: 0.0,
+        }
+    }
+}
+
+/// Every `to` move for the side to move, each paired with its top 6 destroy
+/// squares from the same scored/cached candidate generation the alpha-beta
+/// engines use.
+fn generate_actions(state: &GameState, maximizing: bool) -> Vec<Action> {
+    let moves = state.get_valid_moves(maximizing);
+    let mut actions = Vec::with_capacity(moves.len() * 4);
+    for mv in &moves {
+        for destroy in get_destroy_candidates_advanced_export(state, mv, maximizing, 6) {
+            actions.push(Action { to: mv.to, destroy });
+        }
+    }
+    actions
+}
+
+fn next_rand(seed: &mut u64) -> u64 {
+    *seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+    *seed
+}
+
+fn apply_action(state: &GameState, maximizing: bool, action: Action) -> GameState {
+    let mut next = *state;
+    if maximizing {
+        next.ai = pos_to_mask(action.to.0, action.to.1);
+    } else {
+        next.player = pos_to_mask(action.to.0, action.to.1);
+    }
+    next.destroyed |= pos_to_mask(action.destroy.0, action.destroy.1);
+    next
+}
+
+/// UCT score: `child.wins/child.visits + exploration * sqrt(ln(parent.visits)/child.visits)`.
+/// Unvisited children are explored first.
+fn uct(child: &Node, parent_visits: f64, exploration: f64) -> f64 {
+    if child.visits == 0 {
+        return f64::INFINITY;
+    }
+    let visits = child.visits as f64;
+    child.wins / visits + exploration * (parent_visits.ln() / visits).sqrt()
+}
+
+/// Walks down the tree by UCT while a node is fully expanded, stopping at
+/// the first node with an untried action or no children at all (terminal).
+fn select(arena: &[Node], mut idx: usize, exploration: f64) -> usize {
+    loop {
+        let node = &arena[idx];
+        if !node.untried.is_empty() || node.children.is_empty() {
+            return idx;
+        }
+
+        let parent_visits = node.visits as f64;
+        idx = *node
+            .children
+            .iter()
+            .max_by(|&&a, &&b| {
+                uct(&arena[a], parent_visits, exploration)
+                    .partial_cmp(&uct(&arena[b], parent_visits, exploration))
+                    .unwrap()
+            })
+            .unwrap();
+    }
+}
+
+/// Expands one untried action into a new child, or returns `idx` unchanged
+/// if the node is terminal (no untried actions and no children).
+fn expand(arena: &mut Vec<Node>, idx: usize, seed: &mut u64) -> usize {
+    if arena[idx].untried.is_empty() {
+        return idx;
+    }
+
+    let pick = (next_rand(seed) % arena[idx].untried.len() as u64) as usize;
+    let action = arena[idx].untried.swap_remove(pick);
+
+    let parent_maximizing = arena[idx].maximizing;
+    let child_state = apply_action(&arena[idx].state, parent_maximizing, action);
+    let child_maximizing = !parent_maximizing;
+
+    let child_idx = arena.len();
+    arena.push(Node::new(child_state, child_maximizing, idx, Some(action)));
+    arena[idx].children.push(child_idx);
+    child_idx
+}
+
+/// Random (lightly-heuristic, since destroy candidates are already scored
+/// and truncated to the top few) playout to a terminal state, capped by a
+/// ply budget so a rollout can't run away. Returns `Some(true)`/`Some(false)`
+/// for an AI/player win, or `None` if the ply budget ran out first.
+fn playout(state: &GameState, maximizing: bool, seed: &mut u64) -> Option<bool> {
+    let mut cur = *state;
+    let mut turn = maximizing;
+
+    for _ in 0..80 {
+        let moves = cur.get_valid_moves(turn);
+        if moves.is_empty() {
+            // Side to move is stuck - the other side wins.
+            return Some(!turn);
+        }
+
+        let mv = moves[(next_rand(seed) % moves.len() as u64) as usize];
+        let candidates = get_destroy_candidates_advanced_export(&cur, &mv, turn, 6);
+        let destroy = candidates[(next_rand(seed) % candidates.len() as u64) as usize];
+
+        cur = apply_action(&cur, turn, Action { to: mv.to, destroy });
+        turn = !turn;
+    }
+
+    None
+}
+
+/// Propagates the playout result up the path from `idx` to the root,
+/// flipping perspective per node via each node's own `maximizing` flag.
+fn backpropagate(arena: &mut [Node], mut idx: usize, ai_wins: Option<bool>) {
+    loop {
+        let parent;
+        {
+            let node = &mut arena[idx];
+            node.visits += 1;
+            let mover_was_ai = !node.maximizing;
+            node.wins += match ai_wins {
+                Some(w) => if w == mover_was_ai { 1.0 } else { 0.0 },
+                None => 0.5,
+            };
+            parent = node.parent;
+        }
+
+        if idx == 0 {
+            break;
+        }
+        idx = parent;
+    }
+}
+
+/// Runs MCTS from `state` (the AI is always the side about to move) and
+/// returns the most-visited root child's move, or `None` if the AI has no
+/// legal moves at all.
+pub fn find_best_move_mcts(state: &GameState, config: MctsConfig) -> Option<Move> {
+    let mut arena = vec![Node::new(*state, true, 0, None)];
+    if arena[0].untried.is_empty() {
+        return None;
+    }
+
+    let start_time = js_sys::Date::now();
+    let time_limit = (config.time_limit_ms as f64).max(1.0);
+    let mut seed = 0x9E3779B97F4A7C15u64;
+
+    while js_sys::Date::now() - start_time < time_limit {
+        let leaf = select(&arena, 0, config.exploration);
+        let expanded = expand(&mut arena, leaf, &mut seed);
+
+        let ai_wins = if expanded == leaf {
+            // Terminal: whoever is to move at `leaf` has no moves and loses.
+            Some(!arena[leaf].maximizing)
+        } else {
+            playout(&arena[expanded].state, arena[expanded].maximizing, &mut seed)
+        };
+
+        backpropagate(&mut arena, expanded, ai_wins);
+    }
+
+    let (ai_r, ai_c) = index_to_pos(state.ai.trailing_zeros() as u8);
+
+    arena[0]
+        .children
+        .iter()
+        .max_by_key(|&&idx| arena[idx].visits)
+        .and_then(|&idx| arena[idx].action)
+        .map(|action| Move {
+            from: (ai_r, ai_c),
+            to: action.to,
+            destroy: action.destroy,
+            score: 0,
+        })
+}
@@ -131,9 +131,116 @@ pub fn would_cause_partition(
     result.is_partitioned
 }
 
+/// Find every cell whose destruction would partition the player from the
+/// AI, in a single Tarjan articulation-point DFS instead of `empty_count`
+/// separate `would_cause_partition` calls (each of which runs two full
+/// flood fills) — the difference between O(V + E) and O(V * flood-fill).
+///
+/// Builds the queen-connectivity graph over non-destroyed cells (an edge
+/// joins two cells one queen step apart; a longer slide is a chain of such
+/// steps, so the graph has the same components as full queen moves, while
+/// removing a cell also cuts every slide across it) and walks it from the
+/// player's cell, tracking `disc`/`low` per the standard
+/// algorithm. A non-root cell `u` is an articulation point when some DFS
+/// child `v` has `low[v] >= disc[u]`; the root is one iff it has more than
+/// one DFS child. A cut cell only matters for partition purposes if the AI
+/// sits in the subtree that removal would sever from the player's side, so
+/// the same DFS also tracks which subtree contains the AI cell.
+pub fn find_cut_cells(player_pos: (u8, u8), ai_pos: (u8, u8), destroyed: u64) -> u64 {
+    let player_idx = pos_to_index(player_pos.0, player_pos.1);
+    let ai_idx = pos_to_index(ai_pos.0, ai_pos.1);
+
+    let full_board = (1u64 << CELL_COUNT) - 1;
+    let nodes = full_board & !destroyed;
+
+    if (nodes & (1u64 << player_idx)) == 0 || (nodes & (1u64 << ai_idx)) == 0 {
+        return 0;
+    }
+
+    let mut visited = [false; 49];
+    let mut disc = [0u32; 49];
+    let mut low = [0u32; 49];
+    let mut timer = 0u32;
+    let mut cut_cells = 0u64;
+
+    cut_cell_dfs(
+        player_idx as usize,
+        usize::MAX,
+        ai_idx as usize,
+        nodes,
+        destroyed,
+        &mut visited,
+        &mut disc,
+        &mut low,
+        &mut timer,
+        &mut cut_cells,
+    );
+
+    cut_cells
+}
+
+/// DFS step for `find_cut_cells`. Returns whether the AI cell lies in `u`'s
+/// subtree, so the caller (an ancestor) can tell whether separating this
+/// subtree from the rest of the graph actually separates player from AI.
+#[allow(clippy::too_many_arguments)]
+fn cut_cell_dfs(
+    u: usize,
+    parent: usize,
+    ai_idx: usize,
+    nodes: u64,
+    impassable: u64,
+    visited: &mut [bool; 49],
+    disc: &mut [u32; 49],
+    low: &mut [u32; 49],
+    timer: &mut u32,
+    cut_cells: &mut u64,
+) -> bool {
+    visited[u] = true;
+    *timer += 1;
+    disc[u] = *timer;
+    low[u] = *timer;
+    let mut children = 0u32;
+    let mut subtree_has_ai = u == ai_idx;
+
+    let (r, c) = index_to_pos(u as u8);
+    let mut neighbors = get_queen_moves(r, c, impassable) & nodes;
+
+    while neighbors != 0 {
+        let v = neighbors.trailing_zeros() as usize;
+        neighbors &= neighbors - 1;
+
+        // Single steps only (see `find_cut_cells`).
+        if v == parent || between(u as u8, v as u8) != 0 {
+            continue;
+        }
+
+        if visited[v] {
+            low[u] = low[u].min(disc[v]);
+        } else {
+            children += 1;
+            let child_has_ai = cut_cell_dfs(v, u, ai_idx, nodes, impassable, visited, disc, low, timer, cut_cells);
+            subtree_has_ai |= child_has_ai;
+            low[u] = low[u].min(low[v]);
+
+            let is_root = parent == usize::MAX;
+            let is_articulation = (is_root && children > 1) || (!is_root && low[v] >= disc[u]);
+
+            // `v`'s subtree is never an ancestor of `u`, so if it holds the
+            // AI, removing `u` strands the AI away from the player's side.
+            if is_articulation && child_has_ai {
+                *cut_cells |= 1u64 << u;
+            }
+        }
+    }
+
+    subtree_has_ai
+}
+
 /// Evaluate partition potential - how close is the board to being partitioned
 ///
-/// Checks critical cells and returns a score (0.0 to 1.0) where higher = more likely to partition soon
+/// Returns a score (0.0 to 1.0) where higher = more likely to partition soon:
+/// the fraction of empty cells whose destruction would separate the two
+/// players, via a single `find_cut_cells` traversal.
 pub fn evaluate_partition_potential(
     player_pos: (u8, u8),
     ai_pos: (u8, u8),
@@ -152,31 +259,266 @@ pub fn evaluate_partition_potential(
         return 1.0; // No empty cells, likely already partitioned
     }
 
-    // Count cells that would cause partition if destroyed
-    let mut partition_cells = 0;
-    let mut total_checked = 0;
+    let cut_cells = find_cut_cells(player_pos, ai_pos, destroyed) & empty;
+    count_ones(cut_cells) as f32 / count_ones(empty) as f32
+}
+
+/// One maximal region of the free-cell graph separated from the rest only
+/// by cut cells (articulation points) — finer-grained than
+/// `PartitionResult`'s single region per side, since a board can fracture
+/// into three or more such chambers at once.
+#[derive(Clone, Debug)]
+pub struct Chamber {
+    /// Free cells making up this chamber.
+    pub cells: u64,
+    pub size: u32,
+    /// BFS distance (in queen moves) from the player to this chamber, if reachable.
+    pub player_distance: Option<u32>,
+    /// BFS distance (in queen moves) from the AI to this chamber, if reachable.
+    pub ai_distance: Option<u32>,
+    /// Who gets there first: `Some(true)` = AI, `Some(false)` = player,
+    /// `None` = contested (equal distance or unreachable by either).
+    pub owner: Option<bool>,
+    /// Odd-sized chambers favor whoever enters first, since the other side
+    /// then runs out of moves one ply sooner once confined to it.
+    pub odd: bool,
+}
+
+/// A cell whose removal disconnects the free-cell graph into more pieces —
+/// an articulation point in the graph-theoretic sense.
+#[derive(Clone, Copy, Debug)]
+pub struct CutCell {
+    pub idx: u8,
+    /// Size of the largest player-favoring chamber gated behind this cut
+    /// (0 if every chamber it touches favors the AI or is contested) — how
+    /// much is at stake, from the AI's perspective, in fighting over it.
+    pub value: i32,
+}
+
+/// Chamber decomposition of the board's free cells, separated by cut cells.
+pub struct ChamberGraph {
+    pub chambers: Vec<Chamber>,
+    pub cut_cells: Vec<CutCell>,
+    /// The single cut cell gating the most player-favoring territory, if any.
+    pub best_cut_cell: Option<u8>,
+}
+
+/// Decompose the free cells of the board into chambers separated by
+/// articulation points, via Tarjan's algorithm over the queen-move adjacency
+/// graph, then attribute each chamber to whichever side's BFS reaches it
+/// first.
+///
+/// Unlike `detect_partition_bitboard`, which only answers "split or not"
+/// into exactly two regions, this finds every cut cell and every resulting
+/// chamber at once, so the evaluator can reason about a board with several
+/// near-partitions in flight simultaneously, and surface the single cut cell
+/// most worth contesting.
+pub fn analyze_chambers(state: &GameState, blocked: u64) -> ChamberGraph {
+    let player_idx = safe_get_position_index(state.player);
+    let ai_idx = safe_get_position_index(state.ai);
+    let player_mask = player_idx.map(|i| 1u64 << i).unwrap_or(0);
+    let ai_mask = ai_idx.map(|i| 1u64 << i).unwrap_or(0);
+
+    // A piece's own square is always a graph node (it can move off it), even
+    // though `blocked` (as passed by callers like `evaluate_advanced`) marks
+    // it occupied. Everything else destroyed stays genuinely impassable.
+    //
+    // The off-board bits above CELL_COUNT also have to be marked impassable
+    // here: `get_queen_moves` treats any bit that isn't in `blocked` as a
+    // legal square to slide onto, and north/south shifts aren't column-
+    // masked the way east/west and the diagonals are, so without this a
+    // queen near row 0/6 "slides" into the phantom rows living in bits
+    // 49-63 of the u64 - which then panics on the 49-entry arrays below.
+    let full_board = (1u64 << CELL_COUNT) - 1;
+    let impassable = (blocked & !(player_mask | ai_mask)) | !full_board;
+    let nodes = full_board & !impassable;
+
+    let articulation_points = find_articulation_points(nodes, impassable);
+
+    let player_dist = player_idx.map(|idx| bfs_distances(index_to_pos(idx), impassable));
+    let ai_dist = ai_idx.map(|idx| bfs_distances(index_to_pos(idx), impassable));
+
+    // Chambers are the connected components left once cut cells are
+    // (temporarily) treated as impassable too.
+    let chamber_blocking = impassable | articulation_points;
+    let mut chambers = Vec::new();
+    let mut remaining = nodes & !articulation_points;
+
+    while remaining != 0 {
+        let idx = remaining.trailing_zeros() as u8;
+        let pos = index_to_pos(idx);
+        let cells = queen_flood_fill(pos, chamber_blocking) & remaining;
+        remaining &= !cells;
+
+        let size = count_ones(cells);
+        let player_distance = chamber_min_distance(cells, player_dist.as_ref());
+        let ai_distance = chamber_min_distance(cells, ai_dist.as_ref());
+        let owner = match (player_distance, ai_distance) {
+            (Some(p), Some(a)) if a < p => Some(true),
+            (Some(p), Some(a)) if p < a => Some(false),
+            (None, Some(_)) => Some(true),
+            (Some(_), None) => Some(false),
+            _ => None,
+        };
+
+        chambers.push(Chamber {
+            cells,
+            size,
+            player_distance,
+            ai_distance,
+            owner,
+            odd: size % 2 == 1,
+        });
+    }
+
+    // Each cut cell's value: the largest player-favoring chamber it's
+    // directly adjacent to (one queen move away).
+    let mut cut_cells = Vec::new();
+    let mut remaining_cuts = articulation_points;
+    while remaining_cuts != 0 {
+        let idx = remaining_cuts.trailing_zeros() as u8;
+        remaining_cuts &= remaining_cuts - 1;
 
-    let mut temp = empty;
-    while temp != 0 {
-        let lowest_bit = temp & temp.wrapping_neg();
-        let idx = lowest_bit.trailing_zeros() as u8;
         let (r, c) = index_to_pos(idx);
+        let adjacent = get_queen_moves(r, c, impassable);
 
-        total_checked += 1;
+        let value = chambers.iter()
+            .filter(|chamber| chamber.owner == Some(false) && (chamber.cells & adjacent) != 0)
+            .map(|chamber| chamber.size as i32)
+            .max()
+            .unwrap_or(0);
 
-        // Check if destroying this cell would partition
-        if would_cause_partition(player_pos, ai_pos, destroyed, (r, c)) {
-            partition_cells += 1;
-        }
+        cut_cells.push(CutCell { idx, value });
+    }
 
+    let best_cut_cell = cut_cells.iter()
+        .filter(|c| c.value > 0)
+        .max_by_key(|c| c.value)
+        .map(|c| c.idx);
+
+    ChamberGraph { chambers, cut_cells, best_cut_cell }
+}
+
+/// Smallest BFS distance among `cells`, using the precomputed per-cell
+/// distance table from `bfs_distances` (`None` if unreached or no table).
+fn chamber_min_distance(cells: u64, dist: Option<&[Option<u32>; 49]>) -> Option<u32> {
+    let dist = dist?;
+    let mut temp = cells;
+    let mut best: Option<u32> = None;
+    while temp != 0 {
+        let idx = temp.trailing_zeros() as usize;
         temp &= temp - 1;
+        if let Some(d) = dist[idx] {
+            best = Some(best.map_or(d, |b| b.min(d)));
+        }
     }
+    best
+}
+
+/// Queen-move BFS distance from `start_pos` to every other cell, stepping
+/// ring by ring exactly like `queen_flood_fill` but recording depth.
+fn bfs_distances(start_pos: (u8, u8), impassable: u64) -> [Option<u32>; 49] {
+    let mut dist = [None; 49];
+    let start_idx = pos_to_index(start_pos.0, start_pos.1) as usize;
+    dist[start_idx] = Some(0);
 
-    if total_checked == 0 {
-        return 1.0;
+    let mut visited = 1u64 << start_idx;
+    let mut frontier = visited;
+    let max_iterations = 50;
+    let mut depth = 0u32;
+
+    while frontier != 0 && depth < max_iterations {
+        depth += 1;
+        let mut new_frontier = 0u64;
+        let mut temp = frontier;
+        while temp != 0 {
+            let idx = temp.trailing_zeros() as u8;
+            temp &= temp - 1;
+            let (r, c) = index_to_pos(idx);
+            let moves = get_queen_moves(r, c, impassable | visited);
+            new_frontier |= moves & !visited;
+        }
+
+        let mut t = new_frontier;
+        while t != 0 {
+            let idx = t.trailing_zeros() as usize;
+            t &= t - 1;
+            dist[idx] = Some(depth);
+        }
+
+        visited |= new_frontier;
+        frontier = new_frontier;
     }
 
-    partition_cells as f32 / total_checked as f32
+    dist
+}
+
+/// Tarjan's articulation-point algorithm over the single-step queen-move
+/// graph restricted to `nodes` (see `find_cut_cells`), with `impassable`
+/// cells left out.
+fn find_articulation_points(nodes: u64, impassable: u64) -> u64 {
+    let mut visited = [false; 49];
+    let mut disc = [0u32; 49];
+    let mut low = [0u32; 49];
+    let mut timer = 0u32;
+    let mut articulation = 0u64;
+
+    let mut remaining = nodes;
+    while remaining != 0 {
+        let start = remaining.trailing_zeros() as usize;
+        remaining &= remaining - 1;
+
+        if !visited[start] {
+            tarjan_dfs(start, usize::MAX, nodes, impassable, &mut visited, &mut disc, &mut low, &mut timer, &mut articulation);
+        }
+    }
+
+    articulation
+}
+
+#[allow(clippy::too_many_arguments)]
+fn tarjan_dfs(
+    u: usize,
+    parent: usize,
+    nodes: u64,
+    impassable: u64,
+    visited: &mut [bool; 49],
+    disc: &mut [u32; 49],
+    low: &mut [u32; 49],
+    timer: &mut u32,
+    articulation: &mut u64,
+) {
+    visited[u] = true;
+    *timer += 1;
+    disc[u] = *timer;
+    low[u] = *timer;
+    let mut children = 0u32;
+
+    let (r, c) = index_to_pos(u as u8);
+    let mut neighbors = get_queen_moves(r, c, impassable) & nodes;
+
+    while neighbors != 0 {
+        let v = neighbors.trailing_zeros() as usize;
+        neighbors &= neighbors - 1;
+
+        // Single steps only (see `find_cut_cells`).
+        if v == parent || between(u as u8, v as u8) != 0 {
+            continue;
+        }
+
+        if visited[v] {
+            low[u] = low[u].min(disc[v]);
+        } else {
+            children += 1;
+            tarjan_dfs(v, u, nodes, impassable, visited, disc, low, timer, articulation);
+            low[u] = low[u].min(low[v]);
+
+            let is_root = parent == usize::MAX;
+            if (is_root && children > 1) || (!is_root && low[v] >= disc[u]) {
+                *articulation |= 1u64 << u;
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -269,4 +611,104 @@ mod tests {
         );
         assert!(!would_not_partition);
     }
+
+    #[test]
+    fn test_find_cut_cells_matches_would_cause_partition() {
+        // A single gap at (3,3) in an otherwise destroyed row 3 should be
+        // found as the one cell whose destruction partitions the players,
+        // agreeing with `would_cause_partition` cell-by-cell.
+        let player_pos = (0, 0);
+        let ai_pos = (6, 0);
+
+        let mut destroyed = 0u64;
+        for c in 0..7 {
+            if c != 3 {
+                destroyed |= 1u64 << pos_to_index(3, c);
+            }
+        }
+
+        let cut_cells = find_cut_cells(player_pos, ai_pos, destroyed);
+        let gap_idx = pos_to_index(3, 3);
+        assert_ne!(cut_cells & (1u64 << gap_idx), 0);
+
+        let mut empty = ((1u64 << CELL_COUNT) - 1) & !(destroyed | pos_to_mask(player_pos.0, player_pos.1) | pos_to_mask(ai_pos.0, ai_pos.1));
+        while empty != 0 {
+            let idx = empty.trailing_zeros() as u8;
+            empty &= empty - 1;
+            let (r, c) = index_to_pos(idx);
+
+            let expected = would_cause_partition(player_pos, ai_pos, destroyed, (r, c));
+            let actual = (cut_cells & (1u64 << idx)) != 0;
+            assert_eq!(expected, actual, "mismatch at ({}, {})", r, c);
+        }
+    }
+
+    #[test]
+    fn test_find_cut_cells_empty_board_has_none() {
+        let player_pos = (0, 0);
+        let ai_pos = (6, 6);
+        let cut_cells = find_cut_cells(player_pos, ai_pos, 0u64);
+        assert_eq!(cut_cells, 0);
+    }
+
+    #[test]
+    fn test_analyze_chambers_splits_diagonal_wall() {
+        // A solid diagonal wall two cells thick (a one-cell diagonal is
+        // crossed by diagonal steps such as (0,1)-(1,0)) splits the board
+        // into exactly two chambers, one per side.
+        let player_pos = (0, 6);
+        let ai_pos = (6, 0);
+
+        let mut destroyed = 0u64;
+        for i in 0..7 {
+            destroyed |= 1u64 << pos_to_index(i, i);
+            if i > 0 {
+                destroyed |= 1u64 << pos_to_index(i, i - 1);
+            }
+        }
+
+        let state = GameState {
+            player: pos_to_mask(player_pos.0, player_pos.1),
+            ai: pos_to_mask(ai_pos.0, ai_pos.1),
+            destroyed,
+        };
+        let blocked = destroyed | state.player | state.ai;
+
+        let graph = analyze_chambers(&state, blocked);
+
+        assert_eq!(graph.chambers.len(), 2);
+        let player_chamber = graph.chambers.iter().find(|c| c.owner == Some(false));
+        let ai_chamber = graph.chambers.iter().find(|c| c.owner == Some(true));
+        assert!(player_chamber.is_some());
+        assert!(ai_chamber.is_some());
+    }
+
+    #[test]
+    fn test_analyze_chambers_finds_cut_cell() {
+        // A single-cell corridor at (3,3) is the only way across row 3;
+        // destroying everything else in that row makes (3,3) an
+        // articulation point separating the top and bottom halves.
+        let player_pos = (0, 0);
+        let ai_pos = (6, 0);
+
+        let mut destroyed = 0u64;
+        for c in 0..7 {
+            if c != 3 {
+                destroyed |= 1u64 << pos_to_index(3, c);
+            }
+        }
+
+        let state = GameState {
+            player: pos_to_mask(player_pos.0, player_pos.1),
+            ai: pos_to_mask(ai_pos.0, ai_pos.1),
+            destroyed,
+        };
+        let blocked = destroyed | state.player | state.ai;
+
+        let graph = analyze_chambers(&state, blocked);
+
+        let cut_idx = pos_to_index(3, 3);
+        assert!(graph.cut_cells.iter().any(|c| c.idx == cut_idx));
+        assert!(graph.chambers.len() >= 2);
+    }
 }
@@ -3,19 +3,201 @@ use crate::eval::{evaluate, evaluate_advanced, EvalWeights};
 use crate::bitboard::*;
 use crate::voronoi::*;
 use crate::partition::*;
+use crate::transposition::{Bound, TranspositionTable, update_hash_after_move};
+use crate::endgame::solve_and_cache_partition;
+use std::cell::RefCell;
+use std::cmp::Ordering;
+
+/// Killer-move heuristic for the base engine: the last one or two moves
+/// (identified by `from`/`to`, ignoring `destroy`) that caused a beta cutoff
+/// at each remaining-depth, tried right after the TT move during ordering.
+struct KillerMoves {
+    primary: [[Option<Move>; 2]; 32],
+}
+
+impl KillerMoves {
+    fn new() -> Self {
+        KillerMoves { primary: [[None; 2]; 32] }
+    }
+
+    fn record(&mut self, depth: usize, mv: Move) {
+        if depth >= 32 {
+            return;
+        }
+
+        if let Some(existing) = self.primary[depth][0] {
+            if moves_equal_base(&existing, &mv) {
+                return;
+            }
+        }
+
+        self.primary[depth][1] = self.primary[depth][0];
+        self.primary[depth][0] = Some(mv);
+    }
+
+    fn is_killer(&self, depth: usize, mv: &Move) -> bool {
+        if depth >= 32 {
+            return false;
+        }
+
+        self.primary[depth].iter().any(|killer| {
+            if let Some(k) = killer {
+                moves_equal_base(k, mv)
+            } else {
+                false
+            }
+        })
+    }
+
+    fn clear(&mut self) {
+        self.primary = [[None; 2]; 32];
+    }
+}
+
+/// History heuristic for the base engine: a `[from][to]` score table, bumped
+/// by `depth * depth` on a beta cutoff and demoted by the same amount for
+/// quiet moves tried earlier at that node that didn't cause it, so move
+/// ordering keeps improving across the iterative-deepening depths even when
+/// the TT move and killers miss.
+struct HistoryTable {
+    scores: [[i32; 49]; 49],
+}
+
+impl HistoryTable {
+    fn new() -> Self {
+        HistoryTable { scores: [[0; 49]; 49] }
+    }
+
+    const MAX_HISTORY: i32 = 16_384;
+
+    fn record(&mut self, from_idx: usize, to_idx: usize, depth: u8) {
+        if from_idx >= 49 || to_idx >= 49 {
+            return;
+        }
+        let bonus = ((depth as i32) * (depth as i32)).min(400);
+        self.scores[from_idx][to_idx] = (self.scores[from_idx][to_idx] + bonus).min(Self::MAX_HISTORY);
+    }
+
+    fn record_malus(&mut self, from_idx: usize, to_idx: usize, depth: u8) {
+        if from_idx >= 49 || to_idx >= 49 {
+            return;
+        }
+        let malus = ((depth as i32) * (depth as i32)).min(400);
+        self.scores[from_idx][to_idx] = (self.scores[from_idx][to_idx] - malus).max(-Self::MAX_HISTORY);
+    }
+
+    fn get_score(&self, from_idx: usize, to_idx: usize) -> i32 {
+        if from_idx >= 49 || to_idx >= 49 {
+            return 0;
+        }
+        self.scores[from_idx][to_idx]
+    }
+
+    /// Reset for a brand new game - nothing learned so far still applies.
+    fn clear(&mut self) {
+        self.scores = [[0; 49]; 49];
+    }
+
+    /// Between searches (not between iterative-deepening depths within one
+    /// search, which keep accumulating), halve every entry instead of
+    /// wiping it: still-relevant moves from the last search stay ranked
+    /// above untested ones, just with less confidence than freshly-earned
+    /// cutoffs.
+    fn new_search(&mut self) {
+        for row in self.scores.iter_mut() {
+            for score in row.iter_mut() {
+                *score /= 2;
+            }
+        }
+    }
+}
+
+/// Orders `moves` for a node: the TT move first, then killers for this
+/// depth, then by history score - the TT best-move chain gets re-tried
+/// first, and everything else falls back to whatever has cut off or been
+/// promoted most often in similar positions so far.
+fn order_moves_by_heuristics(
+    mut moves: Vec<Move>,
+    tt_move: Option<&Move>,
+    killers: &KillerMoves,
+    history: &HistoryTable,
+    depth: u8,
+) -> Vec<Move> {
+    moves.sort_by_cached_key(|mv| {
+        let mut score = 0i32;
+        if let Some(best) = tt_move {
+            if moves_equal_base(mv, best) {
+                score += 1_000_000;
+            }
+        }
+        if killers.is_killer(depth as usize, mv) {
+            score += 100_000; // above any history score, below the TT move
+        }
+        let from_idx = pos_to_index(mv.from.0, mv.from.1) as usize;
+        let to_idx = pos_to_index(mv.to.0, mv.to.1) as usize;
+        score += history.get_score(from_idx, to_idx);
+        std::cmp::Reverse(score)
+    });
+    moves
+}
 
 pub struct SearchConfig {
     pub max_depth: u8,
+    /// Absolute, never-exceeded time cap.
     pub time_limit_ms: u32,
+    /// Baseline time budget before the instability/falling-eval multipliers
+    /// in `find_best_move` scale it into an `optimum_time` that decides
+    /// whether another depth is worth starting. Always <= `time_limit_ms`.
+    pub soft_time_limit_ms: u32,
+    /// Overrides the phase-weights `evaluate()` would otherwise pick, so
+    /// self-play tuners (see `tuning::tune_base_weights`) can drive this
+    /// engine with a candidate `EvalWeights` vector instead of the hand-set
+    /// presets.
+    pub weights: Option<EvalWeights>,
+    /// Lazy-SMP worker count for native builds. `1` (the default) keeps the
+    /// existing single-threaded iterative-deepening path, including on
+    /// WASM, which cannot spawn OS threads.
+    pub threads: usize,
 }
 
 pub fn find_best_move(state: &GameState, config: SearchConfig) -> Option<Move> {
+    // Lazy-SMP: opt in via `config.threads > 1` on native builds. The
+    // single-threaded path below is otherwise untouched.
+    #[cfg(not(target_arch = "wasm32"))]
+    if config.threads > 1 {
+        return find_best_move_lazy_smp(state, &config);
+    }
+
     let mut best_move = None;
     let mut _best_score = -1_000_000;
-    
+    let mut last_score = -1_000_000;
+
+    let mut tt = TranspositionTable::new();
+    tt.new_search();
+
+    // Killers reset per search (they're ply/depth-specific and stale fast);
+    // history carries over from this thread's previous search and only
+    // decays (rather than wipes) via `new_search` below, then persists
+    // across depths within this search, so move ordering keeps improving as
+    // iterative deepening goes on.
+    thread_local! {
+        static BASE_HISTORY: RefCell<HistoryTable> = RefCell::new(HistoryTable::new());
+    }
+    let mut killers = KillerMoves::new();
+    let mut history = BASE_HISTORY.with(|h| std::mem::replace(&mut *h.borrow_mut(), HistoryTable::new()));
+    history.new_search();
+
     let start_time = js_sys::Date::now();
     let time_limit = config.time_limit_ms as f64;
-    
+    let soft_time_limit = (config.soft_time_limit_ms as f64).min(time_limit);
+
+    // Best-move instability: grows while the root move keeps changing
+    // between depths, shrinks back toward 1.0 once it settles on one move.
+    let mut instability = 1.0f64;
+    // Falling eval: grows while the root score keeps dropping between
+    // depths, so a sharply worsening position gets more time to resolve.
+    let mut falling_eval = 1.0f64;
+
     // Iterative Deepening
     for depth in 1..=config.max_depth {
         // Quick check before starting next depth
@@ -23,61 +205,316 @@ pub fn find_best_move(state: &GameState, config: SearchConfig) -> Option<Move> {
             break;
         }
 
-        let (m, score) = alpha_beta(state, depth, -1_000_000, 1_000_000, true, start_time, time_limit);
-        
+        let hash = tt.compute_hash(state, true);
+
+        // Aspiration windows: once we have a score from a completed
+        // shallower depth, a full (-1_000_000, 1_000_000) window is almost
+        // always wider than necessary. Search a narrow window around it
+        // first and only fall back to a wider one on a fail-low/fail-high.
+        let (m, score) = if depth > 3 {
+            aspiration_search(state, depth, _best_score, &mut tt, &mut killers, &mut history, hash, config.weights.as_ref(), start_time, time_limit)
+        } else {
+            alpha_beta(state, depth, -1_000_000, 1_000_000, true, &mut tt, &mut killers, &mut history, hash, config.weights.as_ref(), start_time, time_limit)
+        };
+
         // Use result only if we finished (or return partial best?)
         // If we timeout inside alpha_beta, the result might be incomplete (-100_000).
         // Standard ID: Always keep result from previous completed depth.
         // If this depth finished validly (score > -infinity), update.
         // But if time ran out, 'm' might be None or partial.
-        
+
         // For simplicity: If m is Some, take it.
         // We need alpha_beta to return a "Timeout" flag or signal.
         if let Some(mv) = m {
-             best_move = Some(mv);
-             _best_score = score;
+            if depth > 1 {
+                let move_changed = best_move.as_ref().map_or(true, |prev| !moves_equal_base(prev, &mv));
+                instability = if move_changed {
+                    (instability * 1.5).min(2.5)
+                } else {
+                    (instability * 0.85).max(1.0)
+                };
+
+                falling_eval = if score < last_score - 40 {
+                    (falling_eval * 1.3).min(2.0)
+                } else {
+                    (falling_eval * 0.85).max(1.0)
+                };
+            }
+
+            best_move = Some(mv);
+            last_score = score;
+            _best_score = score;
         }
-        
+
         // Re-check time to break loop
         if js_sys::Date::now() - start_time > time_limit {
             break;
         }
+
+        // Soft stop: once elapsed time passes the instability/trend-scaled
+        // optimum, don't start another (expensive) depth even though the
+        // hard cap hasn't been hit yet.
+        let optimum_time = (soft_time_limit * instability * falling_eval).min(time_limit);
+        if js_sys::Date::now() - start_time > optimum_time {
+            break;
+        }
     }
-    
+
+    BASE_HISTORY.with(|h| *h.borrow_mut() = history);
     best_move
 }
 
+/// Classic lazy-SMP skip-block schedule, mirroring `search_advanced`'s:
+/// thread `t` skips depth `d` when `((d + skip_phase[t]) / skip_size[t]) % 2
+/// != 0`, staggering which depths each helper thread explores so the fleet
+/// diversifies instead of all threads duplicating thread 0's work.
+#[cfg(not(target_arch = "wasm32"))]
+const LAZY_SMP_SKIP_SIZE: [u32; 8] = [1, 1, 2, 2, 2, 2, 3, 3];
+#[cfg(not(target_arch = "wasm32"))]
+const LAZY_SMP_SKIP_PHASE: [u32; 8] = [0, 1, 0, 1, 2, 3, 0, 1];
+
+#[cfg(not(target_arch = "wasm32"))]
+fn lazy_smp_should_skip(thread_id: usize, depth: u8) -> bool {
+    let i = thread_id.min(LAZY_SMP_SKIP_SIZE.len() - 1);
+    let skip_size = LAZY_SMP_SKIP_SIZE[i];
+    let skip_phase = LAZY_SMP_SKIP_PHASE[i];
+    ((depth as u32 + skip_phase) / skip_size) % 2 != 0
+}
+
+/// Lazy-SMP iterative deepening for the base engine: `config.threads` worker
+/// threads all run the same instability/aspiration-aware loop as
+/// `find_best_move` concurrently, each against its own `TranspositionTable`,
+/// syncing through a `SharedTranspositionTable` between depths so one
+/// thread's cutoffs show up as hits for the others. Threads follow the
+/// skip-block schedule above to diversify rather than all searching the same
+/// depth the same way; thread 0's result is what gets reported.
+#[cfg(not(target_arch = "wasm32"))]
+fn find_best_move_lazy_smp(state: &GameState, config: &SearchConfig) -> Option<Move> {
+    use crate::transposition::SharedTranspositionTable;
+    use std::sync::{Arc, Mutex};
+
+    let shared_tt = Arc::new(SharedTranspositionTable::new());
+    let result: Arc<Mutex<Option<Move>>> = Arc::new(Mutex::new(None));
+
+    let start_time = js_sys::Date::now();
+    let time_limit = config.time_limit_ms as f64;
+    let soft_time_limit = (config.soft_time_limit_ms as f64).min(time_limit);
+
+    std::thread::scope(|scope| {
+        for thread_id in 0..config.threads.max(1) {
+            let shared_tt = Arc::clone(&shared_tt);
+            let result = Arc::clone(&result);
+
+            scope.spawn(move || {
+                let mut tt = TranspositionTable::new();
+                tt.new_search();
+                let mut killers = KillerMoves::new();
+                let mut history = HistoryTable::new();
+                history.new_search();
+
+                let mut best_move = None;
+                let mut best_score = -1_000_000;
+                let mut last_score = -1_000_000;
+                let mut instability = 1.0f64;
+                let mut falling_eval = 1.0f64;
+
+                for depth in 1..=config.max_depth {
+                    if js_sys::Date::now() - start_time > time_limit {
+                        break;
+                    }
+
+                    if thread_id > 0 && lazy_smp_should_skip(thread_id, depth) {
+                        continue;
+                    }
+
+                    shared_tt.absorb_into(&mut tt);
+
+                    let hash = tt.compute_hash(state, true);
+
+                    let (m, score) = if depth > 3 {
+                        aspiration_search(state, depth, best_score, &mut tt, &mut killers, &mut history, hash, config.weights.as_ref(), start_time, time_limit)
+                    } else {
+                        alpha_beta(state, depth, -1_000_000, 1_000_000, true, &mut tt, &mut killers, &mut history, hash, config.weights.as_ref(), start_time, time_limit)
+                    };
+
+                    shared_tt.publish(&tt);
+
+                    if let Some(mv) = m {
+                        if depth > 1 {
+                            let move_changed = best_move.as_ref().map_or(true, |prev| !moves_equal_base(prev, &mv));
+                            instability = if move_changed {
+                                (instability * 1.5).min(2.5)
+                            } else {
+                                (instability * 0.85).max(1.0)
+                            };
+
+                            falling_eval = if score < last_score - 40 {
+                                (falling_eval * 1.3).min(2.0)
+                            } else {
+                                (falling_eval * 0.85).max(1.0)
+                            };
+                        }
+
+                        best_move = Some(mv);
+                        last_score = score;
+                        best_score = score;
+                    }
+
+                    if js_sys::Date::now() - start_time > time_limit {
+                        break;
+                    }
+
+                    let optimum_time = (soft_time_limit * instability * falling_eval).min(time_limit);
+                    if js_sys::Date::now() - start_time > optimum_time {
+                        break;
+                    }
+                }
+
+                if thread_id == 0 {
+                    *result.lock().unwrap() = best_move;
+                }
+            });
+        }
+    });
+
+    Arc::try_unwrap(result).unwrap().into_inner().unwrap()
+}
+
+/// Searches `depth` within a narrow window around `prev_score` (the previous
+/// completed depth's score), widening and re-searching on a fail-low/high
+/// until the result lands inside the window or the window reopens fully.
+#[allow(clippy::too_many_arguments)]
+fn aspiration_search(
+    state: &GameState,
+    depth: u8,
+    prev_score: i32,
+    tt: &mut TranspositionTable,
+    killers: &mut KillerMoves,
+    history: &mut HistoryTable,
+    hash: u64,
+    weights: Option<&EvalWeights>,
+    start_time: f64,
+    time_limit: f64,
+) -> (Option<Move>, i32) {
+    const INITIAL_WINDOW: i32 = 50;
+    const MAX_WINDOW: i32 = 800;
+
+    let mut window = INITIAL_WINDOW;
+    let mut alpha = prev_score - window;
+    let mut beta = prev_score + window;
+
+    loop {
+        let (m, score) = alpha_beta(state, depth, alpha, beta, true, tt, killers, history, hash, weights, start_time, time_limit);
+
+        if score > alpha && score < beta {
+            // Score landed inside the window: trust the result.
+            return (m, score);
+        }
+
+        if score <= alpha {
+            // Fail low - widen the lower bound
+            alpha = score - window;
+            if alpha < -1_000_000 {
+                alpha = -1_000_000;
+            }
+        } else {
+            // Fail high - widen the upper bound
+            beta = score + window;
+            if beta > 1_000_000 {
+                beta = 1_000_000;
+            }
+        }
+
+        window *= 4;
+
+        if window > MAX_WINDOW {
+            return alpha_beta(state, depth, -1_000_000, 1_000_000, true, tt, killers, history, hash, weights, start_time, time_limit);
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 fn alpha_beta(
-    state: &GameState, 
-    depth: u8, 
-    mut alpha: i32, 
-    beta: i32, 
+    state: &GameState,
+    depth: u8,
+    mut alpha: i32,
+    beta: i32,
     maximizing: bool,
+    tt: &mut TranspositionTable,
+    killers: &mut KillerMoves,
+    history: &mut HistoryTable,
+    hash: u64,
+    weights: Option<&EvalWeights>,
     start_time: f64,
-    time_limit: f64
+    time_limit: f64,
 ) -> (Option<Move>, i32) {
     // Periodically check time (every 1024 nodes? Or just every node for now since JS date is fast enough?)
     // JS Date.now() is a syscall in WASM? Might be slow.
     // Let's check every branch.
-    
+
     // Optimization: Only check if depth > 2?
     if js_sys::Date::now() - start_time > time_limit {
         return (None, if maximizing { -1_000_000 } else { 1_000_000 }); // Return bad score to abort
     }
 
     if depth == 0 {
-        let score = evaluate(state);
-        return (None, if maximizing { score } else { -score });
+        // Quiescence extension: a plain `evaluate()` right here is prone to
+        // horizon effects (a side about to be body-blocked or squeezed into
+        // a tiny region looks fine to a static eval one ply too early), so
+        // keep expanding forcing continuations a little past the horizon
+        // before trusting the static score.
+        let score = quiescence(state, alpha, beta, maximizing, tt, weights, hash, start_time, time_limit, 0);
+        return (None, score);
+    }
+
+    // Probe the transposition table: positions reached by different move
+    // orders (very common once destroyed cells pile up) get re-used instead
+    // of re-searched from scratch.
+    let mut tt_move: Option<Move> = None;
+    if let Some(entry) = tt.probe(hash, depth, alpha, beta) {
+        if entry.depth >= depth {
+            match entry.bound {
+                Bound::Exact => return (entry.best_move.clone(), entry.score),
+                Bound::Lower if entry.score >= beta => return (entry.best_move.clone(), entry.score),
+                Bound::Upper if entry.score <= alpha => return (entry.best_move.clone(), entry.score),
+                _ => {}
+            }
+        }
+        tt_move = entry.best_move.clone();
+    }
+
+    // The TT missed (or only held a shallower bound): check whether the
+    // destroyed cells have already split the board into disconnected
+    // regions. If so the outcome is forced, and caching it at maximal depth
+    // means `tt.probe` above resolves every future visit to this node
+    // directly instead of re-expanding the subtree beneath it.
+    if let Some(score) = solve_and_cache_partition(state, maximizing, tt, hash) {
+        return (None, score);
     }
 
-    let moves = state.get_valid_moves(maximizing);
-    
+    let mut moves = state.get_valid_moves(maximizing);
+
     if moves.is_empty() {
         return (None, -100_000 + (20 - depth as i32));
     }
 
+    // Order moves so a position re-reached through a different move order
+    // gets its TT move tried first, then killers for this depth, then
+    // whatever has scored best in the history table - all ahead of the
+    // heuristic-sorted destroy candidates within each move.
+    let moves = order_moves_by_heuristics(moves, tt_move.as_ref(), killers, history, depth);
+
     let mut best_move = None;
     let mut max_score = -1_000_000;
+    let original_alpha = alpha;
+
+    // The side to move is nearly trapped when it barely has any moves left;
+    // every one of them matters then, so none gets a reduced-depth search.
+    let nearly_trapped = moves.len() <= 3;
+
+    // Moves tried at this node that haven't (yet) caused a cutoff, for the
+    // history malus below if a later move turns out to be the one that does.
+    let mut quiet_tried: Vec<(usize, usize)> = Vec::new();
 
     for mut mv in moves {
         // Defensive: Validate move coordinates
@@ -85,16 +522,19 @@ fn alpha_beta(
             continue;
         }
 
-        let target_positions = get_destroy_candidates(state, &mv, maximizing);
-        
-        for destroy_pos in target_positions {
+        let from_idx = pos_to_index(mv.from.0, mv.from.1) as usize;
+        let to_idx = pos_to_index(mv.to.0, mv.to.1) as usize;
+
+        let target_positions = get_destroy_candidates_scored(state, &mv, maximizing);
+
+        for (candidate_index, (destroy_pos, destroy_score)) in target_positions.into_iter().enumerate() {
             // Check time inside inner loop (critical for high branching factor)
             if js_sys::Date::now() - start_time > time_limit {
                 return (best_move, max_score); // Return best we have so far
             }
 
             mv.destroy = destroy_pos;
-            
+
             let mut new_state = *state;
             if maximizing {
                 new_state.ai = pos_to_mask(mv.to.0, mv.to.1);
@@ -102,31 +542,207 @@ fn alpha_beta(
                 new_state.player = pos_to_mask(mv.to.0, mv.to.1);
             }
             new_state.destroyed |= pos_to_mask(destroy_pos.0, destroy_pos.1);
-            
-            let (_, val) = alpha_beta(&new_state, depth - 1, -beta, -alpha, !maximizing, start_time, time_limit);
-            let score = -val;
+
+            let new_hash = update_hash_after_move(tt, hash, state, &new_state, maximizing, !maximizing);
+
+            // Late Move Reductions: `get_destroy_candidates_advanced` already
+            // sorts candidates by `score_destroy_position`, so candidates
+            // past the first few are unlikely to be best. Try those at a
+            // reduced depth first and only re-search at full depth if they
+            // beat alpha. Winning/forcing candidates (score >= 10,000) and
+            // positions where we're nearly out of moves always get the full
+            // search.
+            let can_reduce = depth >= 3
+                && candidate_index >= 3
+                && destroy_score < 10_000
+                && !nearly_trapped;
+
+            let score = if can_reduce {
+                let r = destroy_lmr_reduction(depth, candidate_index as u32 + 1);
+                let reduced_depth = depth.saturating_sub(1 + r).max(1);
+
+                let (_, val) = alpha_beta(&new_state, reduced_depth, -beta, -alpha, !maximizing, tt, killers, history, new_hash, weights, start_time, time_limit);
+                let reduced_score = -val;
+
+                if reduced_score > alpha {
+                    let (_, val) = alpha_beta(&new_state, depth - 1, -beta, -alpha, !maximizing, tt, killers, history, new_hash, weights, start_time, time_limit);
+                    -val
+                } else {
+                    reduced_score
+                }
+            } else {
+                let (_, val) = alpha_beta(&new_state, depth - 1, -beta, -alpha, !maximizing, tt, killers, history, new_hash, weights, start_time, time_limit);
+                -val
+            };
 
             if score > max_score {
                 max_score = score;
                 best_move = Some(mv.clone());
             }
-            
+
             if score > alpha {
                 alpha = score;
                 if alpha >= beta {
-                    break; 
+                    break;
                 }
             }
         }
-        
+
         if alpha >= beta {
+            // Beta cutoff: `mv` ranks above the TT move and killers next
+            // time this node (or one like it) is reached, and the quiet
+            // moves that were tried first but didn't cut get demoted so
+            // they stop crowding out better candidates.
+            killers.record(depth as usize, mv.clone());
+            for &(qf, qt) in quiet_tried.iter() {
+                if qf == from_idx && qt == to_idx {
+                    continue;
+                }
+                history.record_malus(qf, qt, depth);
+            }
+            history.record(from_idx, to_idx, depth);
             break;
         }
+
+        quiet_tried.push((from_idx, to_idx));
     }
 
+    let bound = if max_score <= original_alpha {
+        Bound::Upper
+    } else if max_score >= beta {
+        Bound::Lower
+    } else {
+        Bound::Exact
+    };
+    tt.store(hash, depth, max_score, bound, best_move.clone());
+
     (best_move, max_score)
 }
 
+/// Compares two moves' `from`/`to` only, ignoring `destroy`/`score` — used
+/// to find the TT-stored best move among freshly generated candidates.
+fn moves_equal_base(a: &Move, b: &Move) -> bool {
+    a.from == b.from && a.to == b.to
+}
+
+/// Late Move Reduction amount for a destroy candidate at `depth`, ranked
+/// `move_index` (1-based) in the score-sorted candidate list:
+/// `r = floor(0.75 + ln(depth) * ln(move_index) / 2.0)`.
+fn destroy_lmr_reduction(depth: u8, move_index: u32) -> u8 {
+    let r = 0.75 + (depth as f64).ln() * (move_index as f64).ln() / 2.0;
+    if r < 0.0 { 0 } else { r.floor() as u8 }
+}
+
+/// Quiescence search run at the `alpha_beta` horizon. Only expands "forcing"
+/// `(move, destroy)` pairs - either side's queen already has very few
+/// squares (`<= 3`), or a destroy candidate scores as a checkmate move
+/// (`>= FORCING_SCORE`) per `score_destroy_position`, whose partition
+/// scoring is commented out, so a destroy that only splits the board does
+/// not count as forcing - and falls back to the static eval once no
+/// forcing continuation remains or `MAX_QDEPTH` is hit, so node counts stay
+/// bounded while trap sequences one ply past the horizon still get caught.
+#[allow(clippy::too_many_arguments)]
+fn quiescence(
+    state: &GameState,
+    alpha: i32,
+    beta: i32,
+    maximizing: bool,
+    tt: &mut TranspositionTable,
+    weights: Option<&EvalWeights>,
+    hash: u64,
+    start_time: f64,
+    time_limit: f64,
+    qdepth: u8,
+) -> i32 {
+    const MAX_QDEPTH: u8 = 4;
+    // Same threshold the LMR gate in `alpha_beta` uses to recognize a
+    // winning/forcing destroy candidate.
+    const FORCING_SCORE: i32 = 10_000;
+
+    let stand_pat = {
+        let score = match weights {
+            Some(w) => {
+                let (raw, _) = evaluate_advanced(state, w);
+                raw
+            }
+            None => evaluate(state),
+        };
+        if maximizing { score } else { -score }
+    };
+
+    if stand_pat >= beta {
+        return stand_pat;
+    }
+
+    let mut alpha = alpha.max(stand_pat);
+
+    if qdepth >= MAX_QDEPTH || js_sys::Date::now() - start_time > time_limit {
+        return alpha;
+    }
+
+    let my_pos = if maximizing { state.ai } else { state.player };
+    let my_idx = match safe_get_position_index(my_pos) {
+        Some(idx) => idx,
+        None => return alpha,
+    };
+    let (my_r, my_c) = index_to_pos(my_idx);
+    let blocked = state.destroyed | state.player | state.ai;
+    let opp_pos = if maximizing { state.player } else { state.ai };
+    let opp_low_mobility = safe_get_position_index(opp_pos).map_or(false, |idx| {
+        let (opp_r, opp_c) = index_to_pos(idx);
+        count_ones(get_queen_moves(opp_r, opp_c, blocked)) <= 3
+    });
+    let low_mobility = count_ones(get_queen_moves(my_r, my_c, blocked)) <= 3 || opp_low_mobility;
+
+    for mv in state.get_valid_moves(maximizing) {
+        if js_sys::Date::now() - start_time > time_limit {
+            break;
+        }
+
+        let scored_candidates = get_destroy_candidates_scored(state, &mv, maximizing);
+
+        let destroy_positions: Vec<(u8, u8)> = if low_mobility {
+            scored_candidates.into_iter().map(|(pos, _)| pos).collect()
+        } else {
+            let forcing: Vec<(u8, u8)> = scored_candidates
+                .into_iter()
+                .filter(|(_, score)| *score >= FORCING_SCORE)
+                .map(|(pos, _)| pos)
+                .collect();
+            if forcing.is_empty() {
+                continue; // Not a forcing line: neither side is near-trapped nor is there a checkmate destroy.
+            }
+            forcing
+        };
+
+        for destroy_pos in destroy_positions {
+            let mut candidate = mv;
+            candidate.destroy = destroy_pos;
+
+            let mut new_state = *state;
+            if maximizing {
+                new_state.ai = pos_to_mask(candidate.to.0, candidate.to.1);
+            } else {
+                new_state.player = pos_to_mask(candidate.to.0, candidate.to.1);
+            }
+            new_state.destroyed |= pos_to_mask(destroy_pos.0, destroy_pos.1);
+
+            let new_hash = update_hash_after_move(tt, hash, state, &new_state, maximizing, !maximizing);
+            let val = quiescence(&new_state, -beta, -alpha, !maximizing, tt, weights, new_hash, start_time, time_limit, qdepth + 1);
+            let child_score = -val;
+
+            if child_score > alpha {
+                alpha = child_score;
+            }
+            if alpha >= beta {
+                return alpha;
+            }
+        }
+    }
+
+    alpha
+}
+
 /// Advanced destroy candidate selection with strategic scoring
 ///
 /// Scores ALL destroy positions based on:
@@ -144,6 +760,21 @@ fn get_destroy_candidates_advanced(
     maximizing: bool,
     candidate_count: usize,
 ) -> Vec<(u8, u8)> {
+    get_destroy_candidates_advanced_scored(state, mv, maximizing, candidate_count)
+        .into_iter()
+        .map(|(pos, _score)| pos)
+        .collect()
+}
+
+/// Same candidate selection as `get_destroy_candidates_advanced`, but keeps
+/// each candidate's `score_destroy_position` score alongside it so callers
+/// can gate search reductions (e.g. LMR) on it.
+fn get_destroy_candidates_advanced_scored(
+    state: &GameState,
+    mv: &Move,
+    maximizing: bool,
+    candidate_count: usize,
+) -> Vec<((u8, u8), i32)> {
     let occupied = state.destroyed | state.player | state.ai | pos_to_mask(mv.to.0, mv.to.1);
 
     let target_pos = if maximizing { state.player } else { state.ai };
@@ -187,7 +818,7 @@ fn get_destroy_candidates_advanced(
     // Take top N candidates
     valid_slice.iter()
         .take(candidate_count)
-        .map(|(pos, _score)| *pos)
+        .copied()
         .collect()
 }
 
@@ -284,8 +915,10 @@ fn manhattan_distance(a: (u8, u8), b: (u8, u8)) -> i32 {
     (a.0 as i32 - b.0 as i32).abs() + (a.1 as i32 - b.1 as i32).abs()
 }
 
-/// Legacy simple destroy candidates (fallback)
-fn get_destroy_candidates(state: &GameState, mv: &Move, maximizing: bool) -> Vec<(u8, u8)> {
+/// Legacy simple destroy candidates (fallback), scored so callers (the LMR
+/// gate in `alpha_beta`) can tell a winning/forcing candidate apart from a
+/// merely plausible one.
+fn get_destroy_candidates_scored(state: &GameState, mv: &Move, maximizing: bool) -> Vec<((u8, u8), i32)> {
     let destroyed_cnt = count_ones(state.destroyed);
     let count = if destroyed_cnt < 10 {
         6
@@ -294,15 +927,230 @@ fn get_destroy_candidates(state: &GameState, mv: &Move, maximizing: bool) -> Vec
     } else {
         12 // Deep endgame search
     };
-    get_destroy_candidates_advanced(state, mv, maximizing, count)
+    get_destroy_candidates_advanced_scored(state, mv, maximizing, count)
 }
 
 /// Export for use in search_advanced module
+///
+/// Candidate generation recurs on the same `(state, mv.to, maximizing)`
+/// shape across many nodes of a deep search, so this goes through the
+/// current thread's `CandidateCache` before falling back to the full scoring
+/// pass in `get_destroy_candidates_advanced`.
 pub fn get_destroy_candidates_advanced_export(
     state: &GameState,
     mv: &Move,
     maximizing: bool,
     candidate_count: usize,
 ) -> Vec<(u8, u8)> {
-    get_destroy_candidates_advanced(state, mv, maximizing, candidate_count)
+    let key = candidate_cache_key(state, mv, maximizing, candidate_count);
+    CANDIDATE_CACHE.with(|cache| {
+        cache.borrow_mut().get_or_compute(&key, || {
+            get_destroy_candidates_advanced(state, mv, maximizing, candidate_count)
+        })
+    })
+}
+
+fn candidate_cache_key(state: &GameState, mv: &Move, maximizing: bool, candidate_count: usize) -> String {
+    format!(
+        "{}|{}|{}|{}|{}|{}|{}",
+        state.player, state.ai, state.destroyed, mv.to.0, mv.to.1, maximizing as u8, candidate_count
+    )
+}
+
+// --- Randomized ternary search trie (TST) candidate cache ---
+//
+// A persistent transposition cache for `get_destroy_candidates_advanced`,
+// keyed by a serialized `(state, move, side, count)` string. Plain TSTs
+// degrade to a linked list on sorted or otherwise adversarial insertion
+// order; this one stays balanced regardless of insertion order via
+// Diethelm's randomized root insertion: when a key lands in a subtree
+// currently holding `n` keys, it becomes that subtree's new root with
+// probability `1/(n+1)` (via `lo`/`hi` rotations), and otherwise recurses
+// down as an ordinary ternary search insert would.
+
+/// One byte of a TST key, plus the standard `lo`/`eq`/`hi` children: `lo`/`hi`
+/// hold keys whose byte at this position compares less/greater, `eq` holds
+/// keys that match this byte and continue to the next position. `size` is
+/// the number of complete keys stored in the subtree rooted here (including
+/// this node's own value, if any), used to weight root-insertion.
+struct TstNode<V> {
+    byte: u8,
+    lo: Option<Box<TstNode<V>>>,
+    eq: Option<Box<TstNode<V>>>,
+    hi: Option<Box<TstNode<V>>>,
+    value: Option<V>,
+    size: u32,
+}
+
+impl<V> TstNode<V> {
+    fn leaf(byte: u8) -> Box<Self> {
+        Box::new(TstNode { byte, lo: None, eq: None, hi: None, value: None, size: 0 })
+    }
+}
+
+fn tst_size<V>(node: &Option<Box<TstNode<V>>>) -> u32 {
+    node.as_ref().map_or(0, |n| n.size)
+}
+
+fn tst_node_size<V>(node: &TstNode<V>) -> u32 {
+    (if node.value.is_some() { 1 } else { 0 }) + tst_size(&node.eq) + tst_size(&node.lo) + tst_size(&node.hi)
+}
+
+fn tst_next_random(seed: &mut u64) -> u64 {
+    *seed = seed.wrapping_mul(6364136223846793005u64).wrapping_add(1442695040888963407u64);
+    *seed
+}
+
+/// True with probability `1 / (n + 1)`: the chance a key inserted into a
+/// subtree of `n` existing keys gets promoted to that subtree's new root.
+fn tst_should_promote(seed: &mut u64, n: u32) -> bool {
+    (tst_next_random(seed) % (n as u64 + 1)) == 0
+}
+
+fn tst_rotate_lo<V>(mut node: Box<TstNode<V>>) -> Box<TstNode<V>> {
+    let mut new_root = node.lo.take().expect("tst_rotate_lo requires a lo child");
+    node.lo = new_root.hi.take();
+    node.size = tst_node_size(&node);
+    new_root.hi = Some(node);
+    new_root.size = tst_node_size(&new_root);
+    new_root
+}
+
+fn tst_rotate_hi<V>(mut node: Box<TstNode<V>>) -> Box<TstNode<V>> {
+    let mut new_root = node.hi.take().expect("tst_rotate_hi requires a hi child");
+    node.hi = new_root.lo.take();
+    node.size = tst_node_size(&node);
+    new_root.lo = Some(node);
+    new_root.size = tst_node_size(&new_root);
+    new_root
+}
+
+/// Inserts `key[pos..]` and unconditionally rotates it up to become the root
+/// of this `lo`/`hi` search level, via the usual byte comparison on the way
+/// down and `lo`/`hi` rotations on the way back up.
+fn tst_insert_at_root<V>(node: Option<Box<TstNode<V>>>, key: &[u8], pos: usize, value: V) -> Box<TstNode<V>> {
+    match node {
+        None => {
+            let mut leaf = TstNode::leaf(key[pos]);
+            if pos + 1 == key.len() {
+                leaf.value = Some(value);
+            } else {
+                leaf.eq = Some(tst_insert_at_root(None, key, pos + 1, value));
+            }
+            leaf.size = tst_node_size(&leaf);
+            leaf
+        }
+        Some(mut n) => match key[pos].cmp(&n.byte) {
+            Ordering::Less => {
+                n.lo = Some(tst_insert_at_root(n.lo.take(), key, pos, value));
+                tst_rotate_lo(n)
+            }
+            Ordering::Greater => {
+                n.hi = Some(tst_insert_at_root(n.hi.take(), key, pos, value));
+                tst_rotate_hi(n)
+            }
+            Ordering::Equal => {
+                if pos + 1 == key.len() {
+                    n.value = Some(value);
+                } else {
+                    n.eq = Some(tst_insert_at_root(n.eq.take(), key, pos + 1, value));
+                }
+                n.size = tst_node_size(&n);
+                n
+            }
+        },
+    }
+}
+
+/// Ordinary randomized TST insert: at each node, flip a `1/(n+1)` coin to
+/// decide whether this key gets root-inserted here instead of recursing
+/// further, keeping the tree balanced independent of insertion order.
+fn tst_insert<V>(node: Option<Box<TstNode<V>>>, key: &[u8], pos: usize, value: V, seed: &mut u64) -> Box<TstNode<V>> {
+    if tst_should_promote(seed, tst_size(&node)) {
+        return tst_insert_at_root(node, key, pos, value);
+    }
+
+    let mut n = node.expect("tst_should_promote(_, 0) is always true for an empty subtree");
+    match key[pos].cmp(&n.byte) {
+        Ordering::Less => {
+            n.lo = Some(tst_insert(n.lo.take(), key, pos, value, seed));
+        }
+        Ordering::Greater => {
+            n.hi = Some(tst_insert(n.hi.take(), key, pos, value, seed));
+        }
+        Ordering::Equal => {
+            if pos + 1 == key.len() {
+                n.value = Some(value);
+            } else {
+                n.eq = Some(tst_insert(n.eq.take(), key, pos + 1, value, seed));
+            }
+        }
+    }
+    n.size = tst_node_size(&n);
+    n
+}
+
+fn tst_get<'a, V>(node: &'a Option<Box<TstNode<V>>>, key: &[u8], pos: usize) -> Option<&'a V> {
+    let n = node.as_ref()?;
+    match key[pos].cmp(&n.byte) {
+        Ordering::Less => tst_get(&n.lo, key, pos),
+        Ordering::Greater => tst_get(&n.hi, key, pos),
+        Ordering::Equal => {
+            if pos + 1 == key.len() {
+                n.value.as_ref()
+            } else {
+                tst_get(&n.eq, key, pos + 1)
+            }
+        }
+    }
+}
+
+/// Persistent candidate-list cache backed by a randomized TST, keyed by a
+/// serialized board-state string. Once `capacity` is reached the whole trie
+/// is dropped rather than threading per-key eviction through the rotations
+/// above; in practice the cache refills quickly and the search rarely
+/// revisits positions from many generations back anyway.
+pub struct CandidateCache {
+    root: Option<Box<TstNode<Vec<(u8, u8)>>>>,
+    len: usize,
+    capacity: usize,
+    seed: u64,
+}
+
+impl CandidateCache {
+    pub fn new(capacity: usize) -> Self {
+        CandidateCache {
+            root: None,
+            len: 0,
+            capacity,
+            seed: 0x9E3779B97F4A7C15u64,
+        }
+    }
+
+    pub fn get_or_compute(&mut self, key: &str, compute: impl FnOnce() -> Vec<(u8, u8)>) -> Vec<(u8, u8)> {
+        if let Some(hit) = tst_get(&self.root, key.as_bytes(), 0) {
+            return hit.clone();
+        }
+
+        let value = compute();
+
+        if self.len >= self.capacity {
+            self.root = None;
+            self.len = 0;
+        }
+
+        self.root = Some(tst_insert(self.root.take(), key.as_bytes(), 0, value.clone(), &mut self.seed));
+        self.len += 1;
+
+        value
+    }
+
+    pub fn clear(&mut self) {
+        self.root = None;
+        self.len = 0;
+    }
+}
+
+thread_local! {
+    static CANDIDATE_CACHE: RefCell<CandidateCache> = RefCell::new(CandidateCache::new(4096));
 }
@@ -7,9 +7,11 @@
 //! - PV (Principal Variation) move ordering
 
 use crate::board::{GameState, Move};
-use crate::eval::{evaluate_advanced, EvalWeights};
+use crate::eval::{evaluate_advanced, score_move_for_ordering, EvalWeights};
 use crate::bitboard::*;
 use crate::transposition::{TranspositionTable, Bound, update_hash_after_move};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::transposition::SharedTranspositionTable;
 use crate::partition::*;
 use crate::endgame::*;
 use serde::{Serialize, Deserialize};
@@ -82,14 +84,37 @@ impl HistoryTable {
         }
     }
 
-    fn record(&mut self, from_idx: usize, to_idx: usize, depth: u8) {
+    // Caps how far gravity lets any entry drift from zero in either direction.
+    const MAX_HISTORY: i32 = 16_384;
+
+    /// Gravity-weighted update shared by `record` and `record_malus`: moves
+    /// the entry toward `bonus` (positive for a cutoff, negative for a
+    /// quiet move that was tried and failed) by an amount that shrinks as
+    /// the entry approaches `±MAX_HISTORY`, so scores settle into a bounded
+    /// range instead of growing forever.
+    fn apply_gravity(&mut self, from_idx: usize, to_idx: usize, depth: u8, good: bool) {
         if from_idx >= 49 || to_idx >= 49 {
             return;
         }
 
-        // Moves that cause cutoffs at deeper depths are more valuable
-        let bonus = (depth as i32) * (depth as i32);
-        self.scores[from_idx][to_idx] += bonus;
+        let magnitude = ((depth as i32) * (depth as i32)).min(400);
+        let bonus = if good { magnitude } else { -magnitude };
+
+        let current = self.scores[from_idx][to_idx];
+        let decay = current * bonus.abs() / Self::MAX_HISTORY;
+        self.scores[from_idx][to_idx] = current + bonus - decay;
+    }
+
+    /// Records a beta cutoff: the move that caused it gets a positive
+    /// gravity-weighted bonus.
+    fn record(&mut self, from_idx: usize, to_idx: usize, depth: u8) {
+        self.apply_gravity(from_idx, to_idx, depth, true);
+    }
+
+    /// Demotes a quiet move that was searched in this node but did not
+    /// cause the cutoff, via the same gravity formula with a negative bonus.
+    fn record_malus(&mut self, from_idx: usize, to_idx: usize, depth: u8) {
+        self.apply_gravity(from_idx, to_idx, depth, false);
     }
 
     fn get_score(&self, from_idx: usize, to_idx: usize) -> i32 {
@@ -104,6 +129,44 @@ impl HistoryTable {
     }
 }
 
+/// Counter-move heuristic table
+///
+/// Indexed by the opponent's previous move's `(from_idx, to_idx)`, storing
+/// the move that most recently produced a beta cutoff in reply to it. Since
+/// this game alternates queen relocations, refutations to a specific
+/// opponent relocation recur often, so this is a cheaper, less coarse
+/// complement to the global history table.
+struct CounterMoveTable {
+    // [prev_from_idx][prev_to_idx] -> reply move
+    table: [[Option<Move>; 49]; 49],
+}
+
+impl CounterMoveTable {
+    fn new() -> Self {
+        CounterMoveTable {
+            table: [[None; 49]; 49],
+        }
+    }
+
+    fn record(&mut self, prev_from_idx: usize, prev_to_idx: usize, mv: Move) {
+        if prev_from_idx >= 49 || prev_to_idx >= 49 {
+            return;
+        }
+        self.table[prev_from_idx][prev_to_idx] = Some(mv);
+    }
+
+    fn get(&self, prev_from_idx: usize, prev_to_idx: usize) -> Option<Move> {
+        if prev_from_idx >= 49 || prev_to_idx >= 49 {
+            return None;
+        }
+        self.table[prev_from_idx][prev_to_idx].clone()
+    }
+
+    fn clear(&mut self) {
+        self.table = [[None; 49]; 49];
+    }
+}
+
 /// Advanced search configuration
 pub struct AdvancedSearchConfig {
     pub max_depth: u8,
@@ -115,6 +178,15 @@ pub struct AdvancedSearchConfig {
     pub use_aspiration: bool,
     pub use_pvs: bool,
     pub use_null_move: bool,
+    pub use_lmr: bool,
+    pub use_quiescence: bool,
+    pub use_futility: bool,
+    pub use_counter_moves: bool,
+    pub use_singular_extensions: bool,
+    /// Lazy-SMP worker count for native builds. `1` (the default) keeps the
+    /// existing single-threaded iterative-deepening path, including on
+    /// WASM, which cannot spawn OS threads.
+    pub threads: usize,
 }
 
 impl AdvancedSearchConfig {
@@ -136,11 +208,44 @@ impl AdvancedSearchConfig {
             use_aspiration: true,
             use_pvs: true,
             use_null_move: true,
+            use_lmr: true,
+            use_quiescence: true,
+            use_futility: true,
+            use_counter_moves: true,
+            use_singular_extensions: true,
+            threads: 1,
+        }
+    }
+}
+
+/// Late Move Reduction table: `reductions[depth][move_number]` gives how
+/// much to shave off `depth - 1` before searching a late, quiet move with a
+/// null window. Built once per thread from `r = round(0.75 + ln(depth) *
+/// ln(move_number) / 2.25)`, the standard logarithmic LMR formula, with
+/// both axes clamped to 63.
+fn compute_lmr_reductions() -> [[u8; 64]; 64] {
+    let mut table = [[0u8; 64]; 64];
+    for (depth, row) in table.iter_mut().enumerate().skip(1) {
+        for (move_number, slot) in row.iter_mut().enumerate().skip(1) {
+            let r = 0.75 + (depth as f64).ln() * (move_number as f64).ln() / 2.25;
+            *slot = r.round().max(0.0) as u8;
         }
     }
+    table
+}
+
+thread_local! {
+    static LMR_REDUCTIONS: [[u8; 64]; 64] = compute_lmr_reductions();
+}
+
+/// Looks up the LMR table, clamping `depth` and `move_number` to [1, 63].
+fn lmr_reduction(depth: u8, move_number: u32) -> u8 {
+    let d = (depth as usize).clamp(1, 63);
+    let m = (move_number as usize).clamp(1, 63);
+    LMR_REDUCTIONS.with(|table| table[d][m])
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct SearchResult {
     pub best_move: Option<Move>,
     pub depth: u8,
@@ -186,6 +291,7 @@ pub fn find_best_move_advanced_detailed(state: &GameState, config: AdvancedSearc
             partition.ai_region,
             true, // is_ai
             config.time_limit_ms / 2,
+            true, // use_warnsdorff
         );
 
         if endgame_result.solved {
@@ -200,10 +306,18 @@ pub fn find_best_move_advanced_detailed(state: &GameState, config: AdvancedSearc
         }
     }
 
+    // Lazy-SMP: opt in via `config.threads > 1` on native builds. The
+    // single-threaded path below is otherwise untouched.
+    #[cfg(not(target_arch = "wasm32"))]
+    if config.threads > 1 {
+        return find_best_move_lazy_smp(state, &config);
+    }
+
     let mut tt = TranspositionTable::new();
     tt.new_search();
     let mut killers = KillerMoves::new();
     let mut history = HistoryTable::new();
+    let mut counter_moves = CounterMoveTable::new();
 
     let mut best_move = None;
     let mut best_score = -1_000_000;
@@ -234,6 +348,7 @@ pub fn find_best_move_advanced_detailed(state: &GameState, config: AdvancedSearc
                 &mut tt,
                 &mut killers,
                 &mut history,
+                &mut counter_moves,
                 hash,
                 start_time,
                 time_limit,
@@ -250,6 +365,8 @@ pub fn find_best_move_advanced_detailed(state: &GameState, config: AdvancedSearc
                 &mut tt,
                 &mut killers,
                 &mut history,
+                &mut counter_moves,
+                None,
                 hash,
                 start_time,
                 time_limit,
@@ -284,6 +401,121 @@ pub fn find_best_move_advanced(state: &GameState, config: AdvancedSearchConfig)
     find_best_move_advanced_detailed(state, config).best_move
 }
 
+/// Classic lazy-SMP skip-block schedule: thread `t` skips depth `d` when
+/// `((d + skipPhase[t]) / skipSize[t]) % 2 != 0`, staggering which depths
+/// each helper thread explores (move ordering is the same in every thread)
+/// so the fleet diversifies instead of all threads duplicating thread 0's work.
+#[cfg(not(target_arch = "wasm32"))]
+const LAZY_SMP_SKIP_SIZE: [u32; 8] = [1, 1, 2, 2, 2, 2, 3, 3];
+#[cfg(not(target_arch = "wasm32"))]
+const LAZY_SMP_SKIP_PHASE: [u32; 8] = [0, 1, 0, 1, 2, 3, 0, 1];
+
+#[cfg(not(target_arch = "wasm32"))]
+fn lazy_smp_should_skip(thread_id: usize, depth: u8) -> bool {
+    let i = thread_id.min(LAZY_SMP_SKIP_SIZE.len() - 1);
+    let skip_size = LAZY_SMP_SKIP_SIZE[i];
+    let skip_phase = LAZY_SMP_SKIP_PHASE[i];
+    ((depth as u32 + skip_phase) / skip_size) % 2 != 0
+}
+
+/// Lazy-SMP iterative deepening: `config.threads` worker threads all search
+/// the root concurrently, each with its own `TranspositionTable` but
+/// syncing through a `SharedTranspositionTable` between depths, and
+/// following the skip-block schedule so they diversify rather than
+/// redundantly searching the same depth the same way. The deepest
+/// completed result across all threads wins.
+#[cfg(not(target_arch = "wasm32"))]
+fn find_best_move_lazy_smp(state: &GameState, config: &AdvancedSearchConfig) -> SearchResult {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    let shared_tt = Arc::new(SharedTranspositionTable::new());
+    let total_nodes = Arc::new(AtomicU32::new(0));
+    let best = Arc::new(Mutex::new(SearchResult {
+        best_move: None,
+        depth: 0,
+        score: -1_000_000,
+        nodes: 0,
+    }));
+
+    let start_time = js_sys::Date::now();
+    let time_limit = config.time_limit_ms as f64;
+
+    std::thread::scope(|scope| {
+        for thread_id in 0..config.threads.max(1) {
+            let shared_tt = Arc::clone(&shared_tt);
+            let total_nodes = Arc::clone(&total_nodes);
+            let best = Arc::clone(&best);
+
+            scope.spawn(move || {
+                let mut tt = TranspositionTable::new();
+                tt.new_search();
+                let mut killers = KillerMoves::new();
+                let mut history = HistoryTable::new();
+                let mut counter_moves = CounterMoveTable::new();
+                let mut nodes = 0u32;
+                let mut local_best_score = -1_000_000;
+
+                for depth in 1..=config.max_depth {
+                    if js_sys::Date::now() - start_time > time_limit {
+                        break;
+                    }
+
+                    if thread_id > 0 && lazy_smp_should_skip(thread_id, depth) {
+                        continue;
+                    }
+
+                    shared_tt.absorb_into(&mut tt);
+
+                    let hash = if config.use_tt { tt.compute_hash(state, true) } else { 0 };
+
+                    let (m, score) = alpha_beta_advanced(
+                        state,
+                        depth,
+                        -1_000_000,
+                        1_000_000,
+                        true,
+                        config,
+                        &mut tt,
+                        &mut killers,
+                        &mut history,
+                        &mut counter_moves,
+                        None,
+                        hash,
+                        start_time,
+                        time_limit,
+                        &mut nodes,
+                    );
+
+                    shared_tt.publish(&tt);
+
+                    let timed_out = js_sys::Date::now() - start_time > time_limit;
+                    if (!timed_out || depth == 1) && m.is_some() {
+                        local_best_score = score;
+
+                        let mut shared_best = best.lock().unwrap();
+                        if shared_best.best_move.is_none() || depth > shared_best.depth {
+                            shared_best.best_move = m;
+                            shared_best.score = score;
+                            shared_best.depth = depth;
+                        }
+                    }
+
+                    if local_best_score > 90_000 || local_best_score < -90_000 {
+                        break;
+                    }
+                }
+
+                total_nodes.fetch_add(nodes, Ordering::Relaxed);
+            });
+        }
+    });
+
+    let mut result = Arc::try_unwrap(best).unwrap().into_inner().unwrap();
+    result.nodes = total_nodes.load(Ordering::Relaxed);
+    result
+}
+
 /// Aspiration window search
 /// Uses narrow alpha-beta windows around previous score to trigger more cutoffs
 #[allow(clippy::too_many_arguments)]
@@ -295,6 +527,7 @@ fn aspiration_search(
     tt: &mut TranspositionTable,
     killers: &mut KillerMoves,
     history: &mut HistoryTable,
+    counter_moves: &mut CounterMoveTable,
     hash: u64,
     start_time: f64,
     time_limit: f64,
@@ -318,6 +551,8 @@ fn aspiration_search(
             tt,
             killers,
             history,
+            counter_moves,
+            None,
             hash,
             start_time,
             time_limit,
@@ -360,6 +595,8 @@ fn aspiration_search(
                 tt,
                 killers,
                 history,
+                counter_moves,
+                None,
                 hash,
                 start_time,
                 time_limit,
@@ -374,6 +611,84 @@ fn aspiration_search(
     }
 }
 
+/// Singular extension verification: searches every legal move OTHER than
+/// `tt_move` at a reduced depth with a null window pinned just below the
+/// TT move's own score. If all of them fail low against that window, the
+/// TT move isn't merely the best move found so far, it's the only one that
+/// keeps the position from collapsing, so the caller extends it by a ply.
+#[allow(clippy::too_many_arguments)]
+fn is_singular_move(
+    state: &GameState,
+    tt_move: &Move,
+    depth: u8,
+    singular_beta: i32,
+    maximizing: bool,
+    config: &AdvancedSearchConfig,
+    tt: &mut TranspositionTable,
+    killers: &mut KillerMoves,
+    history: &mut HistoryTable,
+    counter_moves: &mut CounterMoveTable,
+    start_time: f64,
+    time_limit: f64,
+    nodes: &mut u32,
+) -> bool {
+    let verify_depth = (depth / 2).max(1);
+
+    for mv in state.get_valid_moves(maximizing) {
+        if moves_equal(&mv, tt_move) {
+            continue;
+        }
+
+        if js_sys::Date::now() - start_time > time_limit {
+            return false;
+        }
+
+        for destroy_pos in get_destroy_candidates_advanced(state, &mv, maximizing, 6) {
+            let mut candidate = mv;
+            candidate.destroy = destroy_pos;
+
+            let mut new_state = *state;
+            if maximizing {
+                new_state.ai = pos_to_mask(candidate.to.0, candidate.to.1);
+            } else {
+                new_state.player = pos_to_mask(candidate.to.0, candidate.to.1);
+            }
+            new_state.destroyed |= pos_to_mask(destroy_pos.0, destroy_pos.1);
+
+            let new_hash = if config.use_tt {
+                tt.compute_hash(&new_state, !maximizing)
+            } else {
+                0
+            };
+
+            let (_, val) = alpha_beta_advanced(
+                &new_state,
+                verify_depth,
+                -singular_beta - 1,
+                -singular_beta,
+                !maximizing,
+                config,
+                tt,
+                killers,
+                history,
+                counter_moves,
+                Some(candidate),
+                new_hash,
+                start_time,
+                time_limit,
+                nodes,
+            );
+
+            if -val >= singular_beta {
+                // An alternative holds up on its own: the TT move isn't forced.
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
 /// Advanced alpha-beta search with all optimizations
 #[allow(clippy::too_many_arguments)]
 fn alpha_beta_advanced(
@@ -386,6 +701,8 @@ fn alpha_beta_advanced(
     tt: &mut TranspositionTable,
     killers: &mut KillerMoves,
     history: &mut HistoryTable,
+    counter_moves: &mut CounterMoveTable,
+    prev_move: Option<Move>,
     hash: u64,
     start_time: f64,
     time_limit: f64,
@@ -446,6 +763,29 @@ fn alpha_beta_advanced(
         }
     }
 
+    // Singular extension: if the TT move is a fail-high at nearly our depth,
+    // verify whether every other move fails low against a window pinned just
+    // under the TT score. If so, the TT move is forced, and we search it one
+    // ply deeper below instead of the usual depth - 1. Common in the
+    // partition endgame phase this crate already special-cases, where only
+    // one escape/trapping move avoids a loss.
+    let mut singular_move: Option<Move> = None;
+    if config.use_singular_extensions && config.use_tt && depth >= 6 {
+        if let Some(entry) = tt.peek(hash) {
+            if entry.bound == Bound::Lower && entry.depth >= depth.saturating_sub(3) {
+                if let Some(tt_mv) = entry.best_move {
+                    let singular_beta = entry.score - 2 * (depth as i32);
+                    if is_singular_move(
+                        state, &tt_mv, depth, singular_beta, maximizing, config, tt, killers,
+                        history, counter_moves, start_time, time_limit, nodes,
+                    ) {
+                        singular_move = Some(tt_mv);
+                    }
+                }
+            }
+        }
+    }
+
     // Null Move Pruning
     // Skip if: (1) shallow depth, (2) in check/desperate, (3) endgame
     if config.use_null_move && depth >= 3 && maximizing {
@@ -475,6 +815,8 @@ fn alpha_beta_advanced(
                 tt,
                 killers,
                 history,
+                counter_moves,
+                None,
                 null_hash,
                 start_time,
                 time_limit,
@@ -489,11 +831,51 @@ fn alpha_beta_advanced(
         }
     }
 
-    // Leaf node - evaluate
+    // Futility pruning / razoring near the frontier: at shallow, narrow-
+    // window nodes (PV/aspiration nodes keep the full window and are never
+    // pruned here) with a safe mobility cushion, a static eval far enough
+    // below alpha means no quiet move here can realistically raise it.
+    let is_narrow_window = beta - alpha <= 1;
+    let frontier_static_eval = if config.use_futility && depth <= 2 && is_narrow_window && mobility > 3 {
+        let (raw, _) = evaluate_advanced(state, &config.weights);
+        Some(if maximizing { raw } else { -raw })
+    } else {
+        None
+    };
+
+    if let Some(static_eval) = frontier_static_eval {
+        if depth == 1 {
+            const RAZOR_MARGIN: i32 = 500;
+            if static_eval + RAZOR_MARGIN <= alpha {
+                // So far below alpha that expanding children can't help:
+                // drop straight to the horizon evaluation instead.
+                let score = if config.use_quiescence {
+                    quiescence(
+                        state, alpha, beta, maximizing, config, tt, killers, history, hash,
+                        start_time, time_limit, nodes, 0,
+                    )
+                } else {
+                    static_eval
+                };
+                return (None, score);
+            }
+        }
+    }
+
+    // Leaf node - evaluate, extending into a quiescence phase when the
+    // position is volatile (near-trapped side to move, or a trap one move
+    // away) so forced trap sequences don't fall off the horizon.
     if depth == 0 {
-        let (score, _) = evaluate_advanced(state, &config.weights);
-        let final_score = if maximizing { score } else { -score };
-        return (None, final_score);
+        let score = if config.use_quiescence {
+            quiescence(
+                state, alpha, beta, maximizing, config, tt, killers, history, hash,
+                start_time, time_limit, nodes, 0,
+            )
+        } else {
+            let (raw, _) = evaluate_advanced(state, &config.weights);
+            if maximizing { raw } else { -raw }
+        };
+        return (None, score);
     }
 
     // Generate moves
@@ -510,6 +892,17 @@ fn alpha_beta_advanced(
     let mut max_score = -1_000_000;
     let original_alpha = alpha;
 
+    // Partition threats: moves whose destroy choice would cut the opponent
+    // into a smaller region. Gated like the other deeper-node heuristics
+    // above (singular extensions, null move) since `partition_threats`
+    // floods the board per candidate cut cell and isn't worth paying for
+    // near the horizon.
+    let threats = if depth >= 3 {
+        state.partition_threats(maximizing)
+    } else {
+        Vec::new()
+    };
+
     // Order moves for better alpha-beta pruning
     let ordered_moves = order_moves(
         moves,
@@ -517,20 +910,42 @@ fn alpha_beta_advanced(
         tt,
         killers,
         history,
+        counter_moves,
+        prev_move,
         hash,
         depth,
         maximizing,
         config,
+        &threats,
     );
 
     let mut move_count = 0;
+    // Quiet (non-tactical) moves tried so far this node, for the history
+    // malus below: if one of them turns out not to be the cutoff move, it
+    // gets demoted instead of just missing out on a bonus.
+    let mut quiet_tried: Vec<(usize, usize)> = Vec::new();
 
-    for mut mv in ordered_moves {
+    for (mut mv, is_tactical) in ordered_moves {
         // Defensive: Validate move coordinates
         if mv.to.0 >= BOARD_SIZE || mv.to.1 >= BOARD_SIZE {
             continue;
         }
 
+        if !is_tactical {
+            let from_idx = pos_to_index(mv.from.0, mv.from.1) as usize;
+            let to_idx = pos_to_index(mv.to.0, mv.to.1) as usize;
+            quiet_tried.push((from_idx, to_idx));
+
+            // Futility pruning: this quiet move can't possibly raise alpha
+            // from here, so skip searching it entirely.
+            if let Some(static_eval) = frontier_static_eval {
+                let margin = 150 * (depth as i32);
+                if static_eval + margin <= alpha {
+                    continue;
+                }
+            }
+        }
+
         let destroy_candidates = get_destroy_candidates_advanced(state, &mv, maximizing, 6);
 
         for destroy_pos in destroy_candidates {
@@ -556,12 +971,65 @@ fn alpha_beta_advanced(
                 0
             };
 
-            let score = if config.use_pvs && move_count > 0 && depth >= 3 {
+            // Singular extension: the move that proved forced above searches
+            // one ply deeper than usual instead of the standard depth - 1.
+            // A move that creates a partition threat is searched one ply
+            // deeper than usual, the same create-a-threat/prevent-the-threat
+            // asymmetry a singular extension gives a forced reply: the
+            // opponent's best answer to a threat is often itself one of
+            // their own `partition_threats`, so both sides get the extra
+            // ply needed to resolve whether the cut actually holds.
+            let next_depth = if singular_move.map_or(false, |sm| moves_equal(&sm, &mv)) {
+                depth
+            } else if threats.iter().any(|t| moves_equal(t, &mv)) {
+                depth
+            } else {
+                depth - 1
+            };
+
+            // Late Move Reductions: for late, quiet moves, first try a
+            // reduced-depth null-window search; only pay for the full PVS
+            // logic below if that reduced search beats alpha.
+            let lmr_score = if config.use_lmr && move_count >= 3 && depth >= 3 && !is_tactical {
+                let r = lmr_reduction(depth, move_count as u32);
+                let reduced_depth = depth.saturating_sub(1 + r);
+
+                let (_, val) = alpha_beta_advanced(
+                    &new_state,
+                    reduced_depth,
+                    -alpha - 1,
+                    -alpha,
+                    !maximizing,
+                    config,
+                    tt,
+                    killers,
+                    history,
+                    counter_moves,
+                    Some(mv),
+                    new_hash,
+                    start_time,
+                    time_limit,
+                    nodes,
+                );
+                let reduced = -val;
+
+                if reduced <= alpha {
+                    Some(reduced)
+                } else {
+                    None
+                }
+            } else {
+                None
+            };
+
+            let score = if let Some(reduced) = lmr_score {
+                reduced
+            } else if config.use_pvs && move_count > 0 && depth >= 3 {
                 // Principal Variation Search (PVS)
                 // Search with null window first
                 let (_, val) = alpha_beta_advanced(
                     &new_state,
-                    depth - 1,
+                    next_depth,
                     -alpha - 1,
                     -alpha,
                     !maximizing,
@@ -569,6 +1037,8 @@ fn alpha_beta_advanced(
                     tt,
                     killers,
                     history,
+                    counter_moves,
+                    Some(mv),
                     new_hash,
                     start_time,
                     time_limit,
@@ -580,7 +1050,7 @@ fn alpha_beta_advanced(
                     // Null window failed - re-search with full window
                     let (_, val) = alpha_beta_advanced(
                         &new_state,
-                        depth - 1,
+                        next_depth,
                         -beta,
                         -alpha,
                         !maximizing,
@@ -588,6 +1058,8 @@ fn alpha_beta_advanced(
                         tt,
                         killers,
                         history,
+                        counter_moves,
+                        Some(mv),
                         new_hash,
                         start_time,
                         time_limit,
@@ -601,7 +1073,7 @@ fn alpha_beta_advanced(
                 // First move or PVS disabled - use full window
                 let (_, val) = alpha_beta_advanced(
                     &new_state,
-                    depth - 1,
+                    next_depth,
                     -beta,
                     -alpha,
                     !maximizing,
@@ -609,6 +1081,8 @@ fn alpha_beta_advanced(
                     tt,
                     killers,
                     history,
+                    counter_moves,
+                    Some(mv),
                     new_hash,
                     start_time,
                     time_limit,
@@ -627,13 +1101,30 @@ fn alpha_beta_advanced(
             if score > alpha {
                 alpha = score;
                 if alpha >= beta {
-                    // Beta cutoff - record killer move and history
+                    // Beta cutoff - record killer move, counter-move, and history
                     if config.use_killer_moves {
                         killers.record(depth as usize, mv.clone());
                     }
+                    if config.use_counter_moves {
+                        if let Some(pm) = prev_move {
+                            let prev_from_idx = pos_to_index(pm.from.0, pm.from.1) as usize;
+                            let prev_to_idx = pos_to_index(pm.to.0, pm.to.1) as usize;
+                            counter_moves.record(prev_from_idx, prev_to_idx, mv.clone());
+                        }
+                    }
                     if config.use_history {
                         let from_idx = pos_to_index(mv.from.0, mv.from.1) as usize;
                         let to_idx = pos_to_index(mv.to.0, mv.to.1) as usize;
+
+                        // Demote quiet moves that were tried earlier this
+                        // node and did not cause this cutoff.
+                        for &(qf, qt) in quiet_tried.iter() {
+                            if qf == from_idx && qt == to_idx {
+                                continue;
+                            }
+                            history.record_malus(qf, qt, depth);
+                        }
+
                         history.record(from_idx, to_idx, depth);
                     }
                     break;
@@ -662,6 +1153,110 @@ fn alpha_beta_advanced(
     (best_move, max_score)
 }
 
+/// Quiescence phase at the depth-0 horizon: a mobility analogue of capture
+/// quiescence. The stand-pat score from `evaluate_advanced` is returned
+/// immediately once it already meets `beta`; otherwise, only in a "noisy"
+/// position — the side to move has `<= 3` exits, or some move would drop
+/// the opponent to `<= 1` exit — do we search the handful of escape /
+/// trapping candidates a few plies further, bounded by `qdepth` so this
+/// can't explode into a full search.
+#[allow(clippy::too_many_arguments)]
+fn quiescence(
+    state: &GameState,
+    alpha: i32,
+    beta: i32,
+    maximizing: bool,
+    config: &AdvancedSearchConfig,
+    tt: &mut TranspositionTable,
+    killers: &mut KillerMoves,
+    history: &mut HistoryTable,
+    hash: u64,
+    start_time: f64,
+    time_limit: f64,
+    nodes: &mut u32,
+    qdepth: u8,
+) -> i32 {
+    const MAX_QDEPTH: u8 = 4;
+
+    *nodes += 1;
+
+    let (raw, _) = evaluate_advanced(state, &config.weights);
+    let stand_pat = if maximizing { raw } else { -raw };
+
+    if stand_pat >= beta {
+        return stand_pat;
+    }
+
+    let mut alpha = alpha.max(stand_pat);
+
+    if qdepth >= MAX_QDEPTH {
+        return alpha;
+    }
+
+    if (*nodes & 4095) == 0 && js_sys::Date::now() - start_time > time_limit {
+        return alpha;
+    }
+
+    let my_pos = if maximizing { state.ai } else { state.player };
+    let my_idx = safe_get_position_index(my_pos).unwrap_or(if maximizing { 48 } else { 0 });
+    let (my_r, my_c) = index_to_pos(my_idx);
+    let blocked = state.destroyed | state.player | state.ai;
+    let my_mobility = count_ones(get_queen_moves(my_r, my_c, blocked));
+    let low_mobility = my_mobility <= 3;
+
+    for mv in state.get_valid_moves(maximizing) {
+        if js_sys::Date::now() - start_time > time_limit {
+            break;
+        }
+
+        for destroy_pos in get_destroy_candidates_advanced(state, &mv, maximizing, 6) {
+            let mut candidate = mv;
+            candidate.destroy = destroy_pos;
+
+            let mut new_state = *state;
+            if maximizing {
+                new_state.ai = pos_to_mask(candidate.to.0, candidate.to.1);
+            } else {
+                new_state.player = pos_to_mask(candidate.to.0, candidate.to.1);
+            }
+            new_state.destroyed |= pos_to_mask(destroy_pos.0, destroy_pos.1);
+
+            let is_trapping = {
+                let opp_pos = if maximizing { new_state.player } else { new_state.ai };
+                let opp_idx = safe_get_position_index(opp_pos).unwrap_or(if maximizing { 0 } else { 48 });
+                let (opp_r, opp_c) = index_to_pos(opp_idx);
+                let opp_blocked = new_state.destroyed | new_state.player | new_state.ai;
+                count_ones(get_queen_moves(opp_r, opp_c, opp_blocked)) <= 1
+            };
+
+            if !low_mobility && !is_trapping {
+                continue;
+            }
+
+            let new_hash = if config.use_tt {
+                update_hash_after_move(tt, hash, state, &new_state, maximizing, !maximizing)
+            } else {
+                0
+            };
+
+            let val = quiescence(
+                &new_state, -beta, -alpha, !maximizing, config, tt, killers, history,
+                new_hash, start_time, time_limit, nodes, qdepth + 1,
+            );
+            let child_score = -val;
+
+            if child_score > alpha {
+                alpha = child_score;
+            }
+            if alpha >= beta {
+                return alpha;
+            }
+        }
+    }
+
+    alpha
+}
+
 /// Order moves for optimal alpha-beta pruning
 #[allow(clippy::too_many_arguments)]
 fn order_moves(
@@ -670,22 +1265,36 @@ fn order_moves(
     tt: &TranspositionTable,
     killers: &KillerMoves,
     history: &HistoryTable,
+    counter_moves: &CounterMoveTable,
+    prev_move: Option<Move>,
     hash: u64,
     depth: u8,
     maximizing: bool,
     config: &AdvancedSearchConfig,
-) -> Vec<Move> {
-    let mut scored_moves: Vec<(Move, i32)> = moves
+    threats: &[Move],
+) -> Vec<(Move, bool)> {
+    let counter: Option<Move> = if config.use_counter_moves {
+        prev_move.and_then(|pm| {
+            let prev_from_idx = pos_to_index(pm.from.0, pm.from.1) as usize;
+            let prev_to_idx = pos_to_index(pm.to.0, pm.to.1) as usize;
+            counter_moves.get(prev_from_idx, prev_to_idx)
+        })
+    } else {
+        None
+    };
+    let mut scored_moves: Vec<(Move, i32, bool)> = moves
         .into_iter()
         .map(|mv| {
             let mut score = 0;
+            let mut is_tactical = false;
 
             // 1. PV Move from transposition table (highest priority)
             if config.use_tt {
-                if let Some(entry) = tt.table.get(&hash) {
+                if let Some(entry) = tt.peek(hash) {
                     if let Some(pv_move) = &entry.best_move {
                         if moves_equal(pv_move, &mv) {
                             score += 100_000;
+                            is_tactical = true;
                         }
                     }
                 }
@@ -694,16 +1303,42 @@ fn order_moves(
             // 2. Killer moves
             if config.use_killer_moves && killers.is_killer(depth as usize, &mv) {
                 score += 9_000;
+                is_tactical = true;
+            }
+
+            // 3. Counter-move heuristic: the reply that most recently cut
+            // off the opponent's previous move. Its bonus is below the
+            // killer bonus; it adds to the move's history score, so a move
+            // with a large enough history entry can still rank above it.
+            if let Some(counter_mv) = &counter {
+                if moves_equal(counter_mv, &mv) {
+                    score += 5_000;
+                }
+            }
+
+            // 3b. Partition threat: this move's destroy choice can cut the
+            // opponent into a smaller region (`partition_threats`), ranked
+            // with the other tactical categories above so it's tried before
+            // the quieter moves below.
+            if threats.iter().any(|t| moves_equal(t, &mv)) {
+                score += 4_500;
+                is_tactical = true;
             }
 
-            // 3. History heuristic
+            // 4. History heuristic
             if config.use_history {
                 let from_idx = pos_to_index(mv.from.0, mv.from.1) as usize;
                 let to_idx = pos_to_index(mv.to.0, mv.to.1) as usize;
                 score += history.get_score(from_idx, to_idx);
             }
 
-            // 4. Winning move detection (quick check)
+            // 4b. Cheap positional tiebreak for otherwise-quiet moves: scaled
+            // well below history so it only breaks ties between moves none
+            // of the heuristics above distinguish, instead of falling back
+            // to whatever order move generation happened to produce them in.
+            score += score_move_for_ordering(state, mv.from, mv.to) / 10;
+
+            // 5. Winning move detection (quick check)
             let occupied = state.destroyed | state.player | state.ai | pos_to_mask(mv.to.0, mv.to.1);
             let opp_pos = if maximizing { state.player } else { state.ai };
             let opp_idx = safe_get_position_index(opp_pos).unwrap_or(if maximizing { 0 } else { 48 });
@@ -715,9 +1350,10 @@ fn order_moves(
             if opp_mobility_count == 0 {
                 score += 50_000; // Immediate winning move
                 is_winning = true;
+                is_tactical = true;
             }
 
-            // 5. Survival Instinct (Suicide Prevention) - TypeScript Parity
+            // 6. Survival Instinct (Suicide Prevention) - TypeScript Parity
             // Calculate our mobility AFTER this move.
             // If we move to a spot with 0 exits, it's suicide.
             // CRITICAL FIX: If we are winning (is_winning) OR if the opponent is also desperate (<= 1 move),
@@ -733,6 +1369,7 @@ fn order_moves(
 
                 if my_mobility == 0 {
                     score -= 100_000; // SUICIDE: Do not go here
+                    is_tactical = true;
                 } else if my_mobility == 1 {
                     score -= 20_000; // DANGER: High risk of being trapped
                 } else if my_mobility == 2 {
@@ -740,14 +1377,14 @@ fn order_moves(
                 }
             }
 
-            (mv, score)
+            (mv, score, is_tactical)
         })
         .collect();
 
     // Sort descending by score
     scored_moves.sort_by(|a, b| b.1.cmp(&a.1));
 
-    scored_moves.into_iter().map(|(mv, _)| mv).collect()
+    scored_moves.into_iter().map(|(mv, _, is_tactical)| (mv, is_tactical)).collect()
 }
 
 /// Compare two moves for equality (ignoring destroy and score fields)
@@ -765,3 +1402,148 @@ fn get_destroy_candidates_advanced(
     // Use the sophisticated destroy selection from search.rs
     crate::search::get_destroy_candidates_advanced_export(state, mv, maximizing, candidate_count)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lmr_reduction_grows_with_depth_and_move_number() {
+        // move_number 1 has ln(1) == 0, so the table's base term (0.75)
+        // is all that's left - it always rounds up to a 1-ply reduction,
+        // never 0, for any depth.
+        assert_eq!(lmr_reduction(1, 1), 1);
+        assert_eq!(lmr_reduction(3, 2), 1);
+        // Deeper into the move list at higher depth, the logarithmic term
+        // dominates and the reduction grows.
+        assert_eq!(lmr_reduction(10, 30), 4);
+        assert!(lmr_reduction(10, 30) > lmr_reduction(3, 2));
+    }
+
+    #[test]
+    fn quiescence_returns_stand_pat_at_max_qdepth() {
+        // At the qdepth cap, quiescence must stop recursing and fall back
+        // to the plain stand-pat score, same as evaluate_advanced gives
+        // directly - the "trap horizon" extension is bounded, not open-ended.
+        let state = GameState {
+            player: 1u64 << pos_to_index(0, 0),
+            ai: 1u64 << pos_to_index(6, 6),
+            destroyed: 0,
+        };
+        let config = AdvancedSearchConfig::for_difficulty("NEXUS-3", 1000);
+        let mut tt = TranspositionTable::new();
+        let mut killers = KillerMoves::new();
+        let mut history = HistoryTable::new();
+        let mut nodes = 0u32;
+
+        let (raw, _) = evaluate_advanced(&state, &config.weights);
+        let stand_pat = raw; // maximizing == true
+
+        let score = quiescence(
+            &state, -1_000_000, 1_000_000, true, &config, &mut tt, &mut killers, &mut history,
+            0, 0.0, 1000.0, &mut nodes, 4,
+        );
+
+        assert_eq!(score, stand_pat);
+    }
+
+    #[test]
+    fn history_gravity_bonus_and_malus_are_symmetric_from_zero() {
+        let mut history = HistoryTable::new();
+        assert_eq!(history.get_score(5, 9), 0);
+
+        // depth 10 => magnitude = 10*10 = 100, and decay is zero starting
+        // from an empty entry, so the bonus lands exactly.
+        history.record(5, 9, 10);
+        assert_eq!(history.get_score(5, 9), 100);
+
+        // The malus uses the same gravity formula with the opposite sign;
+        // at this magnitude the decay term truncates to zero, so it
+        // exactly cancels the earlier bonus rather than overshooting.
+        history.record_malus(5, 9, 10);
+        assert_eq!(history.get_score(5, 9), 0);
+    }
+
+    /// A constructed position where the player is reduced to one legal
+    /// move, `(0, 1)` - everything else around their corner is already
+    /// destroyed. The AI's winning move is any legal relocation plus
+    /// destroying `(0, 1)`, which drops the player to zero moves on the
+    /// very next ply. Useful as a "mate in one" fixture for the advanced
+    /// search's pruning heuristics: none of them should ever cause the
+    /// search to miss a forced win that's this shallow.
+    fn corner_trap_state() -> GameState {
+        let mut destroyed = 0u64;
+        destroyed |= 1u64 << pos_to_index(1, 0);
+        destroyed |= 1u64 << pos_to_index(1, 1);
+        // Padding so destroyed_count >= 8, clear of both corners' queen
+        // rays, so find_best_move_advanced_detailed's opening-book
+        // shortcut doesn't override the search this fixture exercises.
+        for &(r, c) in &[(2, 4), (4, 2), (3, 5), (5, 3), (2, 3), (3, 2)] {
+            destroyed |= 1u64 << pos_to_index(r, c);
+        }
+
+        GameState {
+            player: 1u64 << pos_to_index(0, 0),
+            ai: 1u64 << pos_to_index(6, 6),
+            destroyed,
+        }
+    }
+
+    #[test]
+    fn futility_pruning_does_not_miss_a_forced_mate() {
+        let state = corner_trap_state();
+        let config = AdvancedSearchConfig::for_difficulty("NEXUS-3", 3000);
+        assert!(config.use_futility);
+
+        let result = find_best_move_advanced_detailed(&state, config);
+        let best = result.best_move.expect("a legal move exists");
+
+        assert_eq!(best.destroy, (0, 1));
+        assert!(result.score > 50_000, "expected a forced-win score, got {}", result.score);
+    }
+
+    #[test]
+    fn lazy_smp_finds_the_same_forced_mate() {
+        let state = corner_trap_state();
+        let mut config = AdvancedSearchConfig::for_difficulty("NEXUS-3", 3000);
+        config.threads = 2;
+
+        let result = find_best_move_advanced_detailed(&state, config);
+        let best = result.best_move.expect("a legal move exists");
+
+        assert_eq!(best.destroy, (0, 1));
+        assert!(result.score > 50_000, "expected a forced-win score, got {}", result.score);
+    }
+
+    #[test]
+    fn counter_move_table_records_and_overwrites_the_latest_reply() {
+        let mut counters = CounterMoveTable::new();
+        assert!(counters.get(3, 4).is_none());
+
+        let reply_a = Move { from: (0, 0), to: (1, 1), destroy: (2, 2), score: 0 };
+        counters.record(3, 4, reply_a);
+        assert_eq!(counters.get(3, 4).map(|m| m.to), Some((1, 1)));
+
+        // A later cutoff against the same previous move replaces the
+        // stored reply rather than keeping the first one found.
+        let reply_b = Move { from: (0, 0), to: (5, 5), destroy: (6, 6), score: 0 };
+        counters.record(3, 4, reply_b);
+        assert_eq!(counters.get(3, 4).map(|m| m.to), Some((5, 5)));
+    }
+
+    #[test]
+    fn singular_extensions_do_not_miss_a_forced_mate() {
+        // Singular extension only engages at depth >= 6, so this needs a
+        // deeper config than the other pruning-heuristic fixtures above.
+        let state = corner_trap_state();
+        let config = AdvancedSearchConfig::for_difficulty("NEXUS-7", 5000);
+        assert!(config.use_singular_extensions);
+        assert!(config.max_depth >= 6);
+
+        let result = find_best_move_advanced_detailed(&state, config);
+        let best = result.best_move.expect("a legal move exists");
+
+        assert_eq!(best.destroy, (0, 1));
+        assert!(result.score > 50_000, "expected a forced-win score, got {}", result.score);
+    }
+}
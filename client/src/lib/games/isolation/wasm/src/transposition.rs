@@ -7,8 +7,8 @@
 //!
 //! Expected Impact: 30-50% search speedup by avoiding re-evaluation
 
+use crate::bitboard::pos_to_mask;
 use crate::board::{GameState, Move};
-use std::collections::HashMap;
 
 /// Bound type for transposition table entries
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -18,7 +18,9 @@ pub enum Bound {
     Upper,  // Beta bound (score <= this)
 }
 
-/// Transposition table entry
+/// Transposition table entry, reconstructed from a `Slot` plus the hash the
+/// caller already computed. This is the table's external-facing shape;
+/// internally only the compact `Slot` (with its 16-bit key tag) is stored.
 #[derive(Clone, Debug)]
 pub struct TTEntry {
     pub hash: u64,
@@ -29,9 +31,48 @@ pub struct TTEntry {
     pub generation: u8,
 }
 
-/// Transposition Table with Zobrist Hashing
+/// One clustered slot. Only the upper 16 bits of the Zobrist key are kept -
+/// the cluster index a slot lives in already encodes the low bits, so this
+/// is enough to reject the overwhelming majority of false matches while
+/// keeping the slot small enough that a whole cluster fits in a cache line
+/// or two.
+#[derive(Clone, Copy, Debug)]
+struct Slot {
+    key16: u16,
+    depth: u8,
+    score: i32,
+    bound: Bound,
+    best_move: Option<Move>,
+    generation: u8,
+}
+
+/// Fixed number of slots per cluster. Small enough that scanning one on
+/// probe/store is effectively free, large enough to absorb the handful of
+/// same-index collisions a 16-bit tag doesn't already rule out.
+const CLUSTER_SIZE: usize = 4;
+
+#[derive(Clone)]
+struct Cluster {
+    slots: [Option<Slot>; CLUSTER_SIZE],
+}
+
+impl Cluster {
+    fn empty() -> Self {
+        Cluster { slots: [None; CLUSTER_SIZE] }
+    }
+}
+
+/// Default table size, sized the same ballpark as the old ~500k-entry
+/// HashMap default.
+const DEFAULT_MB_BUDGET: usize = 64;
+
+/// Transposition Table with Zobrist Hashing, backed by a flat array of
+/// fixed-size clusters indexed by `hash & mask` instead of a `HashMap`.
+/// Probe and store are both O(`CLUSTER_SIZE`) with no allocation and no
+/// eviction scan: a full cluster replaces its least valuable slot in place.
 pub struct TranspositionTable {
-    pub table: HashMap<u64, TTEntry>,
+    clusters: Vec<Cluster>,
+    mask: u64,
     zobrist_player: [u64; 49],
     zobrist_ai: [u64; 49],
     zobrist_destroyed: [u64; 49],
@@ -39,7 +80,6 @@ pub struct TranspositionTable {
     pub hits: u64,
     pub misses: u64,
     current_generation: u8,
-    max_entries: usize,
 }
 
 impl TranspositionTable {
@@ -66,8 +106,9 @@ impl TranspositionTable {
 
         let zobrist_turn = next_random();
 
-        TranspositionTable {
-            table: HashMap::new(),
+        let mut tt = TranspositionTable {
+            clusters: Vec::new(),
+            mask: 0,
             zobrist_player,
             zobrist_ai,
             zobrist_destroyed,
@@ -75,8 +116,9 @@ impl TranspositionTable {
             hits: 0,
             misses: 0,
             current_generation: 0,
-            max_entries: 500_000, // ~50MB at ~100 bytes per entry
-        }
+        };
+        tt.resize(DEFAULT_MB_BUDGET);
+        tt
     }
 
     /// Compute Zobrist hash for a game state
@@ -102,6 +144,31 @@ impl TranspositionTable {
         hash
     }
 
+    fn cluster_index(&self, hash: u64) -> usize {
+        (hash & self.mask) as usize
+    }
+
+    fn key16(hash: u64) -> u16 {
+        (hash >> 48) as u16
+    }
+
+    fn to_entry(hash: u64, slot: &Slot) -> TTEntry {
+        TTEntry {
+            hash,
+            depth: slot.depth,
+            score: slot.score,
+            bound: slot.bound,
+            best_move: slot.best_move,
+            generation: slot.generation,
+        }
+    }
+
+    fn find_slot(&self, hash: u64) -> Option<&Slot> {
+        let idx = self.cluster_index(hash);
+        let key16 = Self::key16(hash);
+        self.clusters[idx].slots.iter().flatten().find(|s| s.key16 == key16)
+    }
+
     /// Probe the transposition table
     ///
     /// Returns Some(entry) if:
@@ -110,88 +177,101 @@ impl TranspositionTable {
     /// 3. Score bounds are useful (can cause cutoff)
     ///
     /// Always returns entry if it has a best_move (for move ordering)
-    pub fn probe(&mut self, hash: u64, depth: u8, alpha: i32, beta: i32) -> Option<&TTEntry> {
-        if let Some(entry) = self.table.get(&hash) {
-            // Verify hash match (collision detection)
-            if entry.hash != hash {
+    pub fn probe(&mut self, hash: u64, depth: u8, alpha: i32, beta: i32) -> Option<TTEntry> {
+        let slot = match self.find_slot(hash).copied() {
+            Some(slot) => slot,
+            None => {
                 self.misses += 1;
                 return None;
             }
+        };
 
-            // If depth is sufficient, check if we can use the score
-            if entry.depth >= depth {
-                match entry.bound {
-                    Bound::Exact => {
-                        self.hits += 1;
-                        return Some(entry);
-                    }
-                    Bound::Lower if entry.score >= beta => {
-                        self.hits += 1;
-                        return Some(entry);
-                    }
-                    Bound::Upper if entry.score <= alpha => {
-                        self.hits += 1;
-                        return Some(entry);
-                    }
-                    _ => {
-                        // Score not useful, but move might be
-                        if entry.best_move.is_some() {
-                            self.misses += 1;
-                            return Some(entry);
-                        }
-                    }
+        // If depth is sufficient, check if we can use the score
+        if slot.depth >= depth {
+            match slot.bound {
+                Bound::Exact => {
+                    self.hits += 1;
+                    return Some(Self::to_entry(hash, &slot));
+                }
+                Bound::Lower if slot.score >= beta => {
+                    self.hits += 1;
+                    return Some(Self::to_entry(hash, &slot));
+                }
+                Bound::Upper if slot.score <= alpha => {
+                    self.hits += 1;
+                    return Some(Self::to_entry(hash, &slot));
                 }
+                _ => {}
             }
+        }
 
-            // Even if depth is insufficient, return if we have a best move (for ordering)
-            if entry.best_move.is_some() {
-                self.misses += 1;
-                return Some(entry);
-            }
+        // Even if depth is insufficient, return if we have a best move (for ordering)
+        if slot.best_move.is_some() {
+            self.misses += 1;
+            return Some(Self::to_entry(hash, &slot));
         }
 
         self.misses += 1;
         None
     }
 
+    /// Raw lookup that skips the depth/alpha-beta gating and hit/miss
+    /// bookkeeping `probe` does - for callers (singular extensions, move
+    /// ordering) that just want whatever's sitting in the slot, win or not.
+    pub fn peek(&self, hash: u64) -> Option<TTEntry> {
+        self.find_slot(hash).map(|slot| Self::to_entry(hash, slot))
+    }
+
     /// Store an entry in the transposition table
     ///
-    /// Replacement strategy: Depth-preferred with generation tracking
-    /// - Always replace if: (1) no existing entry, (2) deeper search, or (3) same depth + exact score
-    /// - Otherwise keep existing entry (preserves valuable deep searches)
+    /// Replacement strategy, cheapest slot first:
+    /// 1. An existing slot for this key - replace if deeper, same-depth
+    ///    exact, or stale.
+    /// 2. An empty slot in the cluster.
+    /// 3. Otherwise evict the slot minimizing `depth - 8 * relative_age`,
+    ///    where `relative_age` is how many generations old the slot's last
+    ///    write is - this throws out shallow, stale entries first with no
+    ///    scan beyond the handful of slots in the cluster.
     pub fn store(&mut self, hash: u64, depth: u8, score: i32, bound: Bound, best_move: Option<Move>) {
-        // Check if we need to evict entries
-        if self.table.len() >= self.max_entries {
-            self.evict_old_entries();
-        }
-
-        // Check if we should replace existing entry
-        let should_replace = if let Some(existing) = self.table.get(&hash) {
-            // Replace if: (1) deeper search, or (2) same depth + exact score, or (3) old generation
-            depth > existing.depth
+        let idx = self.cluster_index(hash);
+        let key16 = Self::key16(hash);
+        let generation = self.current_generation;
+        let new_slot = Slot { key16, depth, score, bound, best_move, generation };
+        let cluster = &mut self.clusters[idx];
+
+        if let Some(existing_slot) = cluster.slots.iter_mut().find(|s| matches!(s, Some(s) if s.key16 == key16)) {
+            let existing = existing_slot.as_ref().unwrap();
+            let should_replace = depth > existing.depth
                 || (depth == existing.depth && bound == Bound::Exact)
-                || existing.generation < self.current_generation.saturating_sub(2)
-        } else {
-            true // No existing entry
-        };
-
-        if should_replace {
-            let entry = TTEntry {
-                hash,
-                depth,
-                score,
-                bound,
-                best_move,
-                generation: self.current_generation,
-            };
+                || existing.generation < generation.saturating_sub(2);
+            if should_replace {
+                *existing_slot = Some(new_slot);
+            }
+            return;
+        }
 
-            self.table.insert(hash, entry);
+        if let Some(empty_slot) = cluster.slots.iter_mut().find(|s| s.is_none()) {
+            *empty_slot = Some(new_slot);
+            return;
         }
+
+        let victim = cluster
+            .slots
+            .iter_mut()
+            .min_by_key(|s| {
+                let s = s.as_ref().unwrap();
+                let relative_age = generation.wrapping_sub(s.generation) as i32;
+                s.depth as i32 - 8 * relative_age
+            })
+            .unwrap();
+        *victim = Some(new_slot);
     }
 
     /// Clear the transposition table
     pub fn clear(&mut self) {
-        self.table.clear();
+        for cluster in self.clusters.iter_mut() {
+            *cluster = Cluster::empty();
+        }
         self.hits = 0;
         self.misses = 0;
         self.current_generation = 0;
@@ -204,35 +284,6 @@ impl TranspositionTable {
         self.misses = 0;
     }
 
-    /// Evict old entries when table is full
-    fn evict_old_entries(&mut self) {
-        // Keep entries from current and previous generation, remove older ones
-        let min_generation = self.current_generation.saturating_sub(1);
-
-        self.table.retain(|_, entry| {
-            entry.generation >= min_generation || entry.depth >= 6
-        });
-
-        // If still too large, remove lowest depth entries
-        if self.table.len() >= self.max_entries {
-            // Collect keys to remove (avoid borrow checker issues)
-            let mut entries: Vec<_> = self.table.iter()
-                .map(|(hash, entry)| (*hash, entry.depth))
-                .collect();
-            entries.sort_by_key(|(_, depth)| *depth);
-
-            let remove_count = self.table.len() - (self.max_entries * 3 / 4);
-            let keys_to_remove: Vec<u64> = entries.iter()
-                .take(remove_count)
-                .map(|(hash, _)| *hash)
-                .collect();
-
-            for hash in keys_to_remove {
-                self.table.remove(&hash);
-            }
-        }
-    }
-
     /// Get hit rate for statistics
     pub fn hit_rate(&self) -> f64 {
         let total = self.hits + self.misses;
@@ -243,19 +294,185 @@ impl TranspositionTable {
         }
     }
 
-    /// Get table size (number of entries)
+    /// Get table size (number of occupied slots). O(capacity) - a stats
+    /// getter, not a hot path.
     pub fn size(&self) -> usize {
-        self.table.len()
+        self.clusters.iter().map(|c| c.slots.iter().filter(|s| s.is_some()).count()).sum()
+    }
+
+    /// Total slot capacity across every cluster.
+    pub fn capacity(&self) -> usize {
+        self.clusters.len() * CLUSTER_SIZE
+    }
+
+    /// Number of clusters backing this table. Two tables can only `merge_from`
+    /// each other sensibly when this matches.
+    pub fn num_clusters(&self) -> usize {
+        self.clusters.len()
+    }
+
+    /// Resize the table from a megabyte budget. `num_clusters` is rounded
+    /// down to a power of two so `hash & mask` replaces `hash % num_clusters`.
+    /// Drops whatever was stored, same as swapping in a fresh table.
+    pub fn resize(&mut self, mb: usize) {
+        let budget_bytes = mb.max(1) * 1024 * 1024;
+        let cluster_bytes = std::mem::size_of::<Cluster>().max(1);
+        let wanted = (budget_bytes / cluster_bytes).max(1);
+        let num_clusters = if wanted.is_power_of_two() {
+            wanted
+        } else {
+            (wanted.next_power_of_two() / 2).max(1)
+        };
+
+        self.clusters = vec![Cluster::empty(); num_clusters];
+        self.mask = (num_clusters - 1) as u64;
+        self.hits = 0;
+        self.misses = 0;
+    }
+
+    /// Walks the chain of `best_move`s stored for `root` and its successors,
+    /// applying each to a cloned state and re-hashing incrementally via
+    /// `update_hash_after_move`, to recover the principal variation the
+    /// table currently believes in. Stops at the first position with no
+    /// entry or no stored move, if a position repeats (a PV can't contain a
+    /// real cycle), or once `max_len` moves have been collected.
+    pub fn extract_pv(&self, root: &GameState, is_ai_turn: bool, max_len: usize) -> Vec<Move> {
+        let mut pv = Vec::new();
+        let mut seen = std::collections::HashSet::new();
+        let mut state = *root;
+        let mut turn = is_ai_turn;
+        let mut hash = self.compute_hash(&state, turn);
+
+        while pv.len() < max_len {
+            if !seen.insert(hash) {
+                break;
+            }
+
+            let mv = match self.peek(hash).and_then(|e| e.best_move) {
+                Some(mv) => mv,
+                None => break,
+            };
+
+            let mut next_state = state;
+            if turn {
+                next_state.ai = pos_to_mask(mv.to.0, mv.to.1);
+            } else {
+                next_state.player = pos_to_mask(mv.to.0, mv.to.1);
+            }
+            next_state.destroyed |= pos_to_mask(mv.destroy.0, mv.destroy.1);
+
+            let next_turn = !turn;
+            hash = update_hash_after_move(self, hash, &state, &next_state, turn, next_turn);
+
+            pv.push(mv);
+            state = next_state;
+            turn = next_turn;
+        }
+
+        pv
+    }
+
+    /// The inverse of `extract_pv`: re-derives the hash of `root` and every
+    /// position `pv` passes through, then writes each position's move back
+    /// as `Bound::Exact`, tail-first, so the root and other early (more
+    /// valuable) positions are written last and win any in-cluster
+    /// replacement over the tail. Scores aren't re-derived here - this is
+    /// for seeding move ordering and pondering, not replacing a real search
+    /// - so entries are stored with a nominal score of 0.
+    pub fn insert_pv(&mut self, root: &GameState, is_ai_turn: bool, pv: &[Move]) {
+        let mut chain = Vec::with_capacity(pv.len());
+        let mut state = *root;
+        let mut turn = is_ai_turn;
+        let mut hash = self.compute_hash(&state, turn);
+
+        for mv in pv {
+            chain.push(hash);
+
+            let mut next_state = state;
+            if turn {
+                next_state.ai = pos_to_mask(mv.to.0, mv.to.1);
+            } else {
+                next_state.player = pos_to_mask(mv.to.0, mv.to.1);
+            }
+            next_state.destroyed |= pos_to_mask(mv.destroy.0, mv.destroy.1);
+
+            let next_turn = !turn;
+            hash = update_hash_after_move(self, hash, &state, &next_state, turn, next_turn);
+            state = next_state;
+            turn = next_turn;
+        }
+
+        for (i, &node_hash) in chain.iter().enumerate().rev() {
+            let node_depth = (chain.len() - i) as u8;
+            self.store(node_hash, node_depth, 0, Bound::Exact, Some(pv[i]));
+        }
+    }
+
+    /// The engine's expected reply to its own PV move - the second entry of
+    /// `extract_pv`, for a UI to show or a ponder search to start on.
+    pub fn ponder_move(&self, root: &GameState, is_ai_turn: bool) -> Option<Move> {
+        self.extract_pv(root, is_ai_turn, 2).into_iter().nth(1)
+    }
+
+    /// Merges every occupied slot from `other` into `self`, cluster index by
+    /// cluster index, keeping whichever copy searched deeper; a position new
+    /// to a full cluster replaces its shallowest slot only if it searched at
+    /// least as deep. Requires both
+    /// tables to share `num_clusters` (true for any two tables built with
+    /// the same size budget, e.g. lazy-SMP workers). Since slots only carry
+    /// a 16-bit key tag rather than the full hash, merging has to line up
+    /// cluster-for-cluster rather than by recomputing a key to re-insert by.
+    pub fn merge_from(&mut self, other: &TranspositionTable) {
+        debug_assert_eq!(self.clusters.len(), other.clusters.len());
+        for (mine, theirs) in self.clusters.iter_mut().zip(other.clusters.iter()) {
+            for their_slot in theirs.slots.iter().flatten() {
+                // Same key tag already here: keep the deeper copy.
+                if let Some(existing) = mine.slots.iter_mut().flatten().find(|s| s.key16 == their_slot.key16) {
+                    if their_slot.depth >= existing.depth {
+                        *existing = *their_slot;
+                    }
+                    continue;
+                }
+                // Otherwise fill an empty slot, or replace the shallowest one
+                // if theirs searched at least as deep.
+                let target = mine.slots.iter_mut().min_by_key(|s| s.as_ref().map_or(-1, |s| s.depth as i32)).unwrap();
+                if target.as_ref().map_or(true, |s| their_slot.depth >= s.depth) {
+                    *target = Some(*their_slot);
+                }
+            }
+        }
+    }
+}
+
+/// Lock-guarded `TranspositionTable` that lazy-SMP search drivers (in
+/// `search` and `search_advanced`) publish into and absorb from between
+/// iterative-deepening depths. Each worker thread keeps searching on its own
+/// plain `TranspositionTable` so the hot per-node probe/store path stays
+/// lock-free; only at depth boundaries does it sync with this shared table,
+/// via a cluster-wise `merge_from` under one mutex.
+#[cfg(not(target_arch = "wasm32"))]
+pub struct SharedTranspositionTable {
+    table: std::sync::Mutex<TranspositionTable>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl SharedTranspositionTable {
+    pub fn new() -> Self {
+        SharedTranspositionTable {
+            table: std::sync::Mutex::new(TranspositionTable::new()),
+        }
     }
 
-    /// Set maximum number of entries
-    pub fn set_max_entries(&mut self, max_entries: usize) {
-        self.max_entries = max_entries;
+    /// Publishes a worker's local table into the shared one, keeping
+    /// whichever copy searched deeper slot-by-slot.
+    pub fn publish(&self, local: &TranspositionTable) {
+        self.table.lock().unwrap().merge_from(local);
     }
 
-    /// Get maximum number of entries
-    pub fn max_entries(&self) -> usize {
-        self.max_entries
+    /// Absorbs the shared table into a worker's local table before its next
+    /// iteration, so cutoffs found by other threads show up as TT hits here.
+    pub fn absorb_into(&self, local: &mut TranspositionTable) {
+        local.merge_from(&self.table.lock().unwrap());
     }
 }
 
@@ -0,0 +1,346 @@
+//! Self-Play SPSA Tuner for ISOLATION Evaluation Weights
+//!
+//! Learns `EvalWeights` (and the three phase-specific variants produced by
+//! `get_phase_weights`) from self-play instead of the hand-set constants in
+//! `eval.rs`, mirroring the `explore-config` self-tuning tooling used by the
+//! external Entelect-style engines this crate is modeled on.
+//!
+//! Uses Simultaneous Perturbation Stochastic Approximation (SPSA): with ~11
+//! coupled weights, estimating the full gradient by finite differences would
+//! need 22+ evaluations per step, but SPSA estimates it from a single pair of
+//! perturbed matches regardless of dimension.
+
+use crate::board::GameState;
+use crate::eval::{evaluate_advanced, EvalWeights};
+use crate::search::{find_best_move, SearchConfig};
+use crate::search_advanced::{find_best_move_advanced, AdvancedSearchConfig};
+
+/// One SPSA iteration's tunable view of `EvalWeights` as a flat vector.
+const NUM_WEIGHTS: usize = 11;
+
+fn weights_to_vec(w: &EvalWeights) -> [f32; NUM_WEIGHTS] {
+    [
+        w.territory, w.mobility, w.mobility_potential, w.center_control,
+        w.corner_avoidance, w.partition_advantage, w.critical_cells,
+        w.openness, w.parity, w.trap, w.effective_mobility,
+    ]
+}
+
+fn vec_to_weights(v: &[f32; NUM_WEIGHTS]) -> EvalWeights {
+    EvalWeights {
+        territory: v[0].max(0.0),
+        mobility: v[1].max(0.0),
+        mobility_potential: v[2].max(0.0),
+        center_control: v[3].max(0.0),
+        corner_avoidance: v[4].max(0.0),
+        partition_advantage: v[5].max(0.0),
+        critical_cells: v[6].max(0.0),
+        openness: v[7].max(0.0),
+        parity: v[8].max(0.0),
+        trap: v[9].max(0.0),
+        effective_mobility: v[10].max(0.0),
+        rollout: 0.0,
+    }
+}
+
+/// Deterministic +/-1 Bernoulli perturbation, one LCG step per component.
+fn bernoulli_perturbation(seed: &mut u64) -> [f32; NUM_WEIGHTS] {
+    let mut delta = [0.0f32; NUM_WEIGHTS];
+    for d in delta.iter_mut() {
+        *seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        *d = if (*seed >> 63) == 1 { 1.0 } else { -1.0 };
+    }
+    delta
+}
+
+/// `find_best_move_advanced` always searches for the `state.ai` side. To let
+/// either side move with its own weights, mirror the board (swap player/ai)
+/// when it's the "player" side's turn, search normally, then map the
+/// resulting move back onto the real `player`/`ai` fields.
+fn best_move_for_side(state: &GameState, is_ai_turn: bool, weights: &EvalWeights) -> Option<crate::board::Move> {
+    let config = AdvancedSearchConfig {
+        max_depth: 3,
+        time_limit_ms: 20,
+        weights: *weights,
+        use_tt: true,
+        use_killer_moves: true,
+        use_history: true,
+        use_aspiration: false,
+        use_pvs: true,
+        use_null_move: true,
+        use_lmr: true,
+        use_quiescence: true,
+        use_futility: true,
+        use_counter_moves: true,
+        use_singular_extensions: true,
+        threads: 1,
+    };
+
+    if is_ai_turn {
+        find_best_move_advanced(state, config)
+    } else {
+        let mirrored = GameState {
+            player: state.ai,
+            ai: state.player,
+            destroyed: state.destroyed,
+        };
+        find_best_move_advanced(&mirrored, config)
+    }
+}
+
+/// Result of a single self-play game: 1.0 win, 0.5 draw, 0.0 loss (from the
+/// perspective of the side using `weights_a`).
+fn play_game(weights_a: &EvalWeights, weights_b: &EvalWeights, a_is_ai: bool) -> f32 {
+    let mut state = GameState::new();
+    let max_plies = 120;
+
+    for ply in 0..max_plies {
+        let is_ai_turn = ply % 2 == 0;
+        let active_weights = if is_ai_turn == a_is_ai { weights_a } else { weights_b };
+
+        let moves = state.get_valid_moves(is_ai_turn);
+        if moves.is_empty() {
+            // Side to move is stuck: the OTHER side wins.
+            let a_won = is_ai_turn != a_is_ai;
+            return if a_won { 1.0 } else { 0.0 };
+        }
+
+        let mv = match best_move_for_side(&state, is_ai_turn, active_weights) {
+            Some(mv) => mv,
+            None => moves[0].clone(),
+        };
+
+        if is_ai_turn {
+            state.ai = crate::bitboard::pos_to_mask(mv.to.0, mv.to.1);
+        } else {
+            state.player = crate::bitboard::pos_to_mask(mv.to.0, mv.to.1);
+        }
+        state.destroyed |= crate::bitboard::pos_to_mask(mv.destroy.0, mv.destroy.1);
+    }
+
+    // Ply budget exhausted: score by the static evaluation, from A's side.
+    let (score, _) = evaluate_advanced(&state, weights_a);
+    let score = if a_is_ai { score } else { -score };
+    if score > 50 { 1.0 } else if score < -50 { 0.0 } else { 0.5 }
+}
+
+/// Play a small balanced match (each side plays both colors) between two
+/// perturbed weight sets, returning perturbed-weights-A's average score.
+fn balanced_match(weights_a: &EvalWeights, weights_b: &EvalWeights, games_per_eval: u32) -> f32 {
+    let mut total = 0.0;
+    let mut games = 0;
+    for g in 0..games_per_eval.max(2) {
+        let a_is_ai = g % 2 == 0;
+        total += play_game(weights_a, weights_b, a_is_ai);
+        games += 1;
+    }
+    total / games as f32
+}
+
+/// Tune `base` via SPSA self-play, returning the optimized `EvalWeights`.
+///
+/// Schedule follows the standard SPSA decay: `a_k = a/(k+1+A)^0.602` and
+/// `c_k = c/(k+1)^0.101`.
+pub fn tune_weights(base: EvalWeights, iterations: u32, games_per_eval: u32) -> EvalWeights {
+    const A: f32 = 50.0;
+    const SMALL_A: f32 = 8.0;
+    const SMALL_C: f32 = 2.0;
+
+    let mut w = weights_to_vec(&base);
+    let mut seed = 0x9E3779B97F4A7C15u64 ^ (iterations as u64);
+
+    for k in 0..iterations {
+        let a_k = SMALL_A / (k as f32 + 1.0 + A).powf(0.602);
+        let c_k = SMALL_C / (k as f32 + 1.0).powf(0.101);
+
+        let delta = bernoulli_perturbation(&mut seed);
+
+        let mut w_plus = w;
+        let mut w_minus = w;
+        for i in 0..NUM_WEIGHTS {
+            w_plus[i] += c_k * delta[i];
+            w_minus[i] -= c_k * delta[i];
+        }
+
+        let weights_plus = vec_to_weights(&w_plus);
+        let weights_minus = vec_to_weights(&w_minus);
+
+        let y_plus = balanced_match(&weights_plus, &weights_minus, games_per_eval);
+        let y_minus = 1.0 - y_plus;
+
+        for i in 0..NUM_WEIGHTS {
+            let g_i = (y_plus - y_minus) / (2.0 * c_k * delta[i]);
+            w[i] += a_k * g_i;
+        }
+    }
+
+    vec_to_weights(&w)
+}
+
+/// Run the tuner separately per game phase so each bucket in
+/// `get_phase_weights` gets its own optimized vector, and return the
+/// results. Nothing is written to disk; the ignored driver below prints
+/// them.
+#[derive(Debug)]
+pub struct TunedPhaseWeights {
+    pub opening: EvalWeights,
+    pub midgame: EvalWeights,
+    pub endgame: EvalWeights,
+}
+
+pub fn tune_phase_weights(iterations: u32, games_per_eval: u32) -> TunedPhaseWeights {
+    TunedPhaseWeights {
+        opening: tune_weights(crate::eval::get_phase_weights(5), iterations, games_per_eval),
+        midgame: tune_weights(crate::eval::get_phase_weights(20), iterations, games_per_eval),
+        endgame: tune_weights(crate::eval::get_phase_weights(35), iterations, games_per_eval),
+    }
+}
+
+// --- Base-engine variant -----------------------------------------------
+//
+// `tune_weights` above drives `find_best_move_advanced`. The plain
+// `find_best_move` engine (TT, killers and history always on, aspiration
+// windows from depth 4; `SearchConfig` has no switches for them) has its own nexus-style
+// presets via `evaluate()` / `get_phase_weights`, so it gets its own SPSA
+// loop here rather than bolting an `EvalWeights` override onto the advanced
+// path's plumbing.
+
+/// Mirrors `best_move_for_side`, but drives the base `find_best_move` engine
+/// (via `SearchConfig::weights`) instead of `find_best_move_advanced`.
+fn best_move_for_side_base(state: &GameState, is_ai_turn: bool, weights: &EvalWeights) -> Option<crate::board::Move> {
+    let config = SearchConfig {
+        max_depth: 3,
+        time_limit_ms: 20,
+        soft_time_limit_ms: 20,
+        weights: Some(*weights),
+        threads: 1,
+    };
+
+    if is_ai_turn {
+        find_best_move(state, config)
+    } else {
+        let mirrored = GameState {
+            player: state.ai,
+            ai: state.player,
+            destroyed: state.destroyed,
+        };
+        find_best_move(&mirrored, config)
+    }
+}
+
+/// Mirrors `play_game`, but plays with `best_move_for_side_base`.
+fn play_game_base(weights_a: &EvalWeights, weights_b: &EvalWeights, a_is_ai: bool) -> f32 {
+    let mut state = GameState::new();
+    let max_plies = 120;
+
+    for ply in 0..max_plies {
+        let is_ai_turn = ply % 2 == 0;
+        let active_weights = if is_ai_turn == a_is_ai { weights_a } else { weights_b };
+
+        let moves = state.get_valid_moves(is_ai_turn);
+        if moves.is_empty() {
+            let a_won = is_ai_turn != a_is_ai;
+            return if a_won { 1.0 } else { 0.0 };
+        }
+
+        let mv = match best_move_for_side_base(&state, is_ai_turn, active_weights) {
+            Some(mv) => mv,
+            None => moves[0].clone(),
+        };
+
+        if is_ai_turn {
+            state.ai = crate::bitboard::pos_to_mask(mv.to.0, mv.to.1);
+        } else {
+            state.player = crate::bitboard::pos_to_mask(mv.to.0, mv.to.1);
+        }
+        state.destroyed |= crate::bitboard::pos_to_mask(mv.destroy.0, mv.destroy.1);
+    }
+
+    let (score, _) = evaluate_advanced(&state, weights_a);
+    let score = if a_is_ai { score } else { -score };
+    if score > 50 { 1.0 } else if score < -50 { 0.0 } else { 0.5 }
+}
+
+/// Mirrors `balanced_match`, but plays with `play_game_base`.
+fn balanced_match_base(weights_a: &EvalWeights, weights_b: &EvalWeights, games_per_eval: u32) -> f32 {
+    let mut total = 0.0;
+    let mut games = 0;
+    for g in 0..games_per_eval.max(2) {
+        let a_is_ai = g % 2 == 0;
+        total += play_game_base(weights_a, weights_b, a_is_ai);
+        games += 1;
+    }
+    total / games as f32
+}
+
+/// Tune `base` via SPSA self-play against the plain `find_best_move` engine,
+/// using the same decay schedule as `tune_weights`.
+pub fn tune_base_weights(base: EvalWeights, iterations: u32, games_per_eval: u32) -> EvalWeights {
+    const A: f32 = 50.0;
+    const SMALL_A: f32 = 8.0;
+    const SMALL_C: f32 = 2.0;
+
+    let mut w = weights_to_vec(&base);
+    let mut seed = 0xC2B2AE3D27D4EB4Fu64 ^ (iterations as u64);
+
+    for k in 0..iterations {
+        let a_k = SMALL_A / (k as f32 + 1.0 + A).powf(0.602);
+        let c_k = SMALL_C / (k as f32 + 1.0).powf(0.101);
+
+        let delta = bernoulli_perturbation(&mut seed);
+
+        let mut w_plus = w;
+        let mut w_minus = w;
+        for i in 0..NUM_WEIGHTS {
+            w_plus[i] += c_k * delta[i];
+            w_minus[i] -= c_k * delta[i];
+        }
+
+        let weights_plus = vec_to_weights(&w_plus);
+        let weights_minus = vec_to_weights(&w_minus);
+
+        let y_plus = balanced_match_base(&weights_plus, &weights_minus, games_per_eval);
+        let y_minus = 1.0 - y_plus;
+
+        for i in 0..NUM_WEIGHTS {
+            let g_i = (y_plus - y_minus) / (2.0 * c_k * delta[i]);
+            w[i] += a_k * g_i;
+        }
+    }
+
+    vec_to_weights(&w)
+}
+
+#[cfg(test)]
+mod phase_tuning_driver {
+    use super::*;
+
+    /// Self-play SPSA driver for the advanced engine: not a correctness
+    /// check, just a reproducible way to regenerate `find_best_move_advanced`'s
+    /// per-phase nexus-style presets empirically. Run explicitly (`cargo test
+    /// tune_and_report_phase_weights -- --ignored --nocapture`) since it
+    /// plays real self-play games.
+    #[test]
+    #[ignore]
+    fn tune_and_report_phase_weights() {
+        let tuned = tune_phase_weights(50, 4);
+        println!("tuned phase EvalWeights: {:?}", tuned);
+    }
+}
+
+#[cfg(test)]
+mod base_tuning_driver {
+    use super::*;
+
+    /// Self-play SPSA driver for the base engine: not a correctness check,
+    /// just a reproducible way to regenerate its nexus-style presets
+    /// empirically. Run explicitly (`cargo test tune_and_report_base_weights
+    /// -- --ignored --nocapture`) since it plays real self-play games.
+    #[test]
+    #[ignore]
+    fn tune_and_report_base_weights() {
+        let base = crate::eval::get_phase_weights(20);
+        let tuned = tune_base_weights(base, 50, 4);
+        println!("tuned base EvalWeights: {:?}", tuned);
+    }
+}
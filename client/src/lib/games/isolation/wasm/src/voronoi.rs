@@ -18,6 +18,99 @@ pub struct VoronoiResult {
     pub contested_count: i32,
 }
 
+/// Reverse of each `expand_queen_per_direction` direction index (N/S/E/W/
+/// NE/NW/SE/SW), so walking a backpointer means stepping one square the
+/// opposite way the claiming frontier expanded.
+const REVERSE_DIRECTION: [usize; 8] = [1, 0, 3, 2, 7, 6, 5, 4];
+
+/// Row/col delta for each direction index, matching `bitboard::RAY_DELTAS`.
+const DIRECTION_DELTA: [(i8, i8); 8] = [
+    (1, 0), (-1, 0), (0, 1), (0, -1),
+    (1, 1), (1, -1), (-1, 1), (-1, -1),
+];
+
+/// Per-cell BFS backpointer: the depth (in queen moves) it was first
+/// claimed at, and which direction the claiming frontier expanded from.
+/// `None` (depth 0, claimed = false) for cells never reached.
+#[derive(Clone, Copy, Debug, Default)]
+struct PathCell {
+    claimed: bool,
+    depth: u8,
+    /// 3-bit direction code into `expand_queen_per_direction`'s ordering.
+    dir: u8,
+}
+
+/// Per-cell shortest-queen-move backpointers for both players, as produced
+/// by `calculate_voronoi_with_paths`. Reconstructing the path to a target
+/// cell is a backward walk: slide in the reverse of the stored direction
+/// back to the first square claimed in an earlier round (one queen move),
+/// repeat until the source square is reached.
+#[derive(Clone, Debug)]
+pub struct VoronoiPaths {
+    player_cells: [PathCell; 49],
+    ai_cells: [PathCell; 49],
+    player_source: u8,
+    ai_source: u8,
+}
+
+impl VoronoiPaths {
+    /// Reconstructs the shortest queen-move path from the player's (or AI's,
+    /// if `for_ai`) piece to `target`, as the ordered `(row, col)` squares it
+    /// stops on: the source, each queen move's landing square, `target` last
+    /// (so its length is one more than the number of moves). Returns `None`
+    /// if `target` was never claimed by that side.
+    pub fn reconstruct_path(&self, for_ai: bool, target: u8) -> Option<Vec<(u8, u8)>> {
+        let (cells, source) = if for_ai {
+            (&self.ai_cells, self.ai_source)
+        } else {
+            (&self.player_cells, self.player_source)
+        };
+
+        if target != source && !cells[target as usize].claimed {
+            return None;
+        }
+
+        let mut path = vec![target];
+        let mut cur = target;
+        while cur != source {
+            let cell = cells[cur as usize];
+            let (dr, dc) = DIRECTION_DELTA[REVERSE_DIRECTION[cell.dir as usize]];
+            // Slide back along the claiming ray to where this move started:
+            // the first square claimed in an earlier round, or the source.
+            loop {
+                let (r, c) = index_to_pos(cur);
+                cur = pos_to_index((r as i8 + dr) as u8, (c as i8 + dc) as u8);
+                if cur == source || cells[cur as usize].depth < cell.depth {
+                    break;
+                }
+            }
+            path.push(cur);
+        }
+
+        path.reverse();
+        Some(path.into_iter().map(index_to_pos).collect())
+    }
+}
+
+/// How to resolve a `contested` cell once the dual-frontier sweep finishes.
+/// Because both frontiers advance in lockstep, a cell only ever lands in
+/// `contested` when both players reach it in the exact same BFS round - a
+/// genuine same-depth tie, not an artifact of the sweep. This enum decides
+/// what happens to those true ties so search results stay reproducible
+/// instead of silently depending on iteration order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ContestedTiePolicy {
+    /// Leave tied cells in `contested` (the original behavior).
+    KeepContested,
+    /// Reading-order tie-break: whichever piece sits at the lower
+    /// `pos_to_index` claims every remaining tie.
+    ReadingOrder,
+    /// The player always wins remaining ties.
+    PlayerWins,
+    /// The AI always wins remaining ties.
+    AiWins,
+}
+
 /// Calculate Voronoi territories using optimized bitboard-based BFS
 ///
 /// This is ~50-70% faster than distance-based approaches because:
@@ -30,6 +123,7 @@ pub fn calculate_voronoi_optimized(
     player_pos: (u8, u8),
     ai_pos: (u8, u8),
     destroyed: u64,
+    tie_policy: ContestedTiePolicy,
 ) -> VoronoiResult {
     // Create blocked bitboard (destroyed + both positions)
     let player_idx = pos_to_index(player_pos.0, player_pos.1);
@@ -95,6 +189,10 @@ pub fn calculate_voronoi_optimized(
         ai_frontier = new_ai_frontier;
     }
 
+    let (player_territory, ai_territory, contested) = resolve_contested_ties(
+        player_territory, ai_territory, contested, player_idx, ai_idx, tie_policy,
+    );
+
     VoronoiResult {
         player_territory,
         ai_territory,
@@ -105,6 +203,31 @@ pub fn calculate_voronoi_optimized(
     }
 }
 
+/// Applies a `ContestedTiePolicy` to the cells the dual-frontier sweep left
+/// tied, folding them into one side's territory (or leaving them contested)
+/// so the result is deterministic regardless of policy.
+fn resolve_contested_ties(
+    player_territory: u64,
+    ai_territory: u64,
+    contested: u64,
+    player_idx: u8,
+    ai_idx: u8,
+    tie_policy: ContestedTiePolicy,
+) -> (u64, u64, u64) {
+    let awarded_to_player = match tie_policy {
+        ContestedTiePolicy::KeepContested => return (player_territory, ai_territory, contested),
+        ContestedTiePolicy::ReadingOrder => player_idx < ai_idx,
+        ContestedTiePolicy::PlayerWins => true,
+        ContestedTiePolicy::AiWins => false,
+    };
+
+    if awarded_to_player {
+        (player_territory | contested, ai_territory, 0)
+    } else {
+        (player_territory, ai_territory | contested, 0)
+    }
+}
+
 /// Expand frontier by one queen-move step (bit-parallel optimization)
 ///
 /// Returns only new cells (not already visited).
@@ -116,6 +239,173 @@ fn expand_frontier_optimized(
     expand_queen_bit_parallel(frontier, blocked) & !visited
 }
 
+/// Same dual-frontier Voronoi sweep as `calculate_voronoi_optimized`, but
+/// also records each newly-claimed cell's BFS depth and claiming direction
+/// so the caller can reconstruct a shortest queen-move path afterward (e.g.
+/// to the nearest contested cell). Kept as a separate function rather than
+/// a flag on the hot path so `calculate_voronoi_optimized` stays exactly as
+/// cheap as before - call this only when a path is actually needed.
+pub fn calculate_voronoi_with_paths(
+    player_pos: (u8, u8),
+    ai_pos: (u8, u8),
+    destroyed: u64,
+) -> (VoronoiResult, VoronoiPaths) {
+    let player_idx = pos_to_index(player_pos.0, player_pos.1);
+    let ai_idx = pos_to_index(ai_pos.0, ai_pos.1);
+
+    let player_mask = 1u64 << player_idx;
+    let ai_mask = 1u64 << ai_idx;
+    // The bits above the 49 board cells count as blocked, so no fill (the
+    // north one in particular) claims a square past the 49-entry arrays.
+    let blocked = destroyed | player_mask | ai_mask | !((1u64 << CELL_COUNT) - 1);
+
+    let mut player_frontier = player_mask;
+    let mut ai_frontier = ai_mask;
+    let mut player_visited = player_mask;
+    let mut ai_visited = ai_mask;
+
+    let mut player_territory = 0u64;
+    let mut ai_territory = 0u64;
+    let mut contested = 0u64;
+
+    let mut player_cells = [PathCell::default(); 49];
+    let mut ai_cells = [PathCell::default(); 49];
+
+    let max_depth = 20;
+    let mut depth: u8 = 0;
+
+    while (player_frontier != 0 || ai_frontier != 0) && depth < max_depth {
+        depth += 1;
+
+        let player_per_dir = expand_queen_per_direction(player_frontier, blocked);
+        let ai_per_dir = expand_queen_per_direction(ai_frontier, blocked);
+
+        let new_player_frontier = if player_frontier != 0 {
+            record_claims(&player_per_dir, player_visited, depth, &mut player_cells)
+        } else {
+            0
+        };
+
+        let new_ai_frontier = if ai_frontier != 0 {
+            record_claims(&ai_per_dir, ai_visited, depth, &mut ai_cells)
+        } else {
+            0
+        };
+
+        let player_only = new_player_frontier & !ai_visited & !new_ai_frontier;
+        let ai_only = new_ai_frontier & !player_visited & !new_player_frontier;
+        let contested_new = new_player_frontier & new_ai_frontier;
+
+        player_territory |= player_only;
+        ai_territory |= ai_only;
+        contested |= contested_new;
+
+        player_visited |= new_player_frontier;
+        ai_visited |= new_ai_frontier;
+
+        player_frontier = new_player_frontier;
+        ai_frontier = new_ai_frontier;
+    }
+
+    let result = VoronoiResult {
+        player_territory,
+        ai_territory,
+        contested,
+        player_count: count_ones(player_territory) as i32,
+        ai_count: count_ones(ai_territory) as i32,
+        contested_count: count_ones(contested) as i32,
+    };
+
+    let paths = VoronoiPaths {
+        player_cells,
+        ai_cells,
+        player_source: player_idx,
+        ai_source: ai_idx,
+    };
+
+    (result, paths)
+}
+
+/// Claims every not-yet-visited cell across the 8 per-direction fills into
+/// `cells` (depth + claiming direction), returning the union as the new
+/// frontier. A cell reachable from more than one direction this round keeps
+/// the first direction found, in the fixed N/S/E/W/NE/NW/SE/SW order - any
+/// of them yields a valid (if not unique) shortest path.
+fn record_claims(per_direction: &[u64; 8], visited: u64, depth: u8, cells: &mut [PathCell; 49]) -> u64 {
+    let mut new_frontier = 0u64;
+    for (dir, &fill) in per_direction.iter().enumerate() {
+        let mut new_cells = fill & !visited & !new_frontier;
+        new_frontier |= new_cells;
+
+        while new_cells != 0 {
+            let idx = new_cells.trailing_zeros() as usize;
+            cells[idx] = PathCell { claimed: true, depth, dir: dir as u8 };
+            new_cells &= new_cells - 1;
+        }
+    }
+    new_frontier
+}
+
+/// Immediate (single-step) 8-neighborhood of a cell - as opposed to the
+/// full queen slide `get_queen_moves` computes - used to tell whether a
+/// `destroyed` cell sits directly on the border between two territories.
+fn immediate_neighbors(idx: u8) -> u64 {
+    let (r, c) = index_to_pos(idx);
+    let mut mask = 0u64;
+    for &(dr, dc) in DIRECTION_DELTA.iter() {
+        let (rr, cc) = (r as i8 + dr, c as i8 + dc);
+        if rr >= 0 && rr < BOARD_SIZE as i8 && cc >= 0 && cc < BOARD_SIZE as i8 {
+            mask |= pos_to_mask(rr as u8, cc as u8);
+        }
+    }
+    mask
+}
+
+/// Every `destroyed` cell directly bordering both players' territory - a
+/// candidate "thin wall" GNU Go's break-in analysis would flag: open this
+/// one cell back up and the two territories touch.
+fn wall_candidates(result: &VoronoiResult, destroyed: u64) -> u64 {
+    let mut walls = 0u64;
+    let mut remaining = destroyed;
+    while remaining != 0 {
+        let idx = remaining.trailing_zeros() as u8;
+        remaining &= remaining - 1;
+
+        let neighbors = immediate_neighbors(idx);
+        if neighbors & result.player_territory != 0 && neighbors & result.ai_territory != 0 {
+            walls |= 1u64 << idx;
+        }
+    }
+    walls
+}
+
+/// Ports GNU Go's "break-in" idea to Isolation: flags claimed cells that
+/// are only nominally safe because a single `destroyed` cell walls them
+/// off from the opponent's territory. For each such wall cell, provisionally
+/// treat it as passable and slide a queen move out from it in every
+/// direction - any territory cell that reach overruns (on either side) is
+/// only safe as long as that one cell stays standing, so the evaluator
+/// should discount it rather than counting it at full weight.
+pub fn detect_breakins(result: &VoronoiResult, destroyed: u64) -> u64 {
+    let mut fragile = 0u64;
+    let mut walls = wall_candidates(result, destroyed);
+
+    while walls != 0 {
+        let idx = walls.trailing_zeros() as u8;
+        walls &= walls - 1;
+        let wall_mask = 1u64 << idx;
+
+        // Open this one wall cell and see how far a queen slide from it
+        // reaches into either territory.
+        let opened_blocked = destroyed & !wall_mask;
+        let exposed = expand_queen_bit_parallel(wall_mask, opened_blocked);
+
+        fragile |= exposed & (result.player_territory | result.ai_territory);
+    }
+
+    fragile
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -127,7 +417,7 @@ mod tests {
         let ai_pos = (6, 6);
         let destroyed = 0u64;
 
-        let result = calculate_voronoi_optimized(player_pos, ai_pos, destroyed);
+        let result = calculate_voronoi_optimized(player_pos, ai_pos, destroyed, ContestedTiePolicy::KeepContested);
 
         // Player should control top-left area, AI should control bottom-right
         assert!(result.player_count > 0);
@@ -145,7 +435,7 @@ mod tests {
         let ai_pos = (3, 6);
         let destroyed = 0u64;
 
-        let result = calculate_voronoi_optimized(player_pos, ai_pos, destroyed);
+        let result = calculate_voronoi_optimized(player_pos, ai_pos, destroyed, ContestedTiePolicy::KeepContested);
 
         // Should have significant contested territory in the middle
         assert!(result.contested_count > 0);
@@ -166,10 +456,73 @@ mod tests {
         destroyed &= !(1u64 << pos_to_index(0, 0));
         destroyed &= !(1u64 << pos_to_index(6, 6));
 
-        let result = calculate_voronoi_optimized(player_pos, ai_pos, destroyed);
+        let result = calculate_voronoi_optimized(player_pos, ai_pos, destroyed, ContestedTiePolicy::KeepContested);
 
         // With diagonal wall, territories should be more separated
         assert!(result.player_count > 0);
         assert!(result.ai_count > 0);
     }
+
+    #[test]
+    fn test_detect_breakins_flags_gap_in_thin_wall() {
+        // Player at (0,3), AI at (6,3), separated by a destroyed row 3
+        // except for one gap at (3,6) off to the side - opening that one
+        // cell lets each side's queen slide straight into the other's
+        // territory along row 3.
+        let player_pos = (0, 3);
+        let ai_pos = (6, 3);
+
+        let mut destroyed = 0u64;
+        for c in 0..7 {
+            if c != 6 {
+                destroyed |= 1u64 << pos_to_index(3, c);
+            }
+        }
+
+        let result = calculate_voronoi_optimized(player_pos, ai_pos, destroyed, ContestedTiePolicy::KeepContested);
+        let fragile = detect_breakins(&result, destroyed);
+
+        assert_ne!(fragile, 0);
+    }
+
+    #[test]
+    fn test_detect_breakins_no_walls_means_no_fragile_cells() {
+        // No destroyed cells at all - no wall candidates, so nothing to flag.
+        let player_pos = (0, 0);
+        let ai_pos = (6, 6);
+        let destroyed = 0u64;
+
+        let result = calculate_voronoi_optimized(player_pos, ai_pos, destroyed, ContestedTiePolicy::KeepContested);
+        let fragile = detect_breakins(&result, destroyed);
+
+        assert_eq!(fragile, 0);
+    }
+
+    #[test]
+    fn test_contested_tie_policy_resolves_deterministically() {
+        // Player at (3,0), AI at (3,6): symmetric along row 3, so the
+        // equidistant cells are genuinely tied.
+        let player_pos = (3, 0);
+        let ai_pos = (3, 6);
+        let destroyed = 0u64;
+
+        let kept = calculate_voronoi_optimized(player_pos, ai_pos, destroyed, ContestedTiePolicy::KeepContested);
+        assert!(kept.contested_count > 0);
+
+        let player_wins = calculate_voronoi_optimized(player_pos, ai_pos, destroyed, ContestedTiePolicy::PlayerWins);
+        assert_eq!(player_wins.contested_count, 0);
+        assert_eq!(player_wins.player_count, kept.player_count + kept.contested_count);
+        assert_eq!(player_wins.ai_count, kept.ai_count);
+
+        let ai_wins = calculate_voronoi_optimized(player_pos, ai_pos, destroyed, ContestedTiePolicy::AiWins);
+        assert_eq!(ai_wins.contested_count, 0);
+        assert_eq!(ai_wins.ai_count, kept.ai_count + kept.contested_count);
+        assert_eq!(ai_wins.player_count, kept.player_count);
+
+        // Player sits at the lower pos_to_index (row 3, col 0 vs col 6), so
+        // reading-order should agree with PlayerWins here.
+        let reading_order = calculate_voronoi_optimized(player_pos, ai_pos, destroyed, ContestedTiePolicy::ReadingOrder);
+        assert_eq!(reading_order.player_count, player_wins.player_count);
+        assert_eq!(reading_order.ai_count, player_wins.ai_count);
+    }
 }